@@ -0,0 +1,93 @@
+//! Assertion protocol for Smalltalk
+//! 
+//! Smalltalk code checks invariants with `self assert: condition`, which
+//! signals an AssertionFailure when the condition is false. Here the failure
+//! is returned in the `Err` variant, so callers can catch it by matching
+//! on the result or propagate it with `?`.
+
+use super::boolean::BooleanObject;
+use super::exception::AssertionFailed;
+
+/// Description used when an assertion fails without one
+pub const DEFAULT_ASSERTION_DESCRIPTION: &str = "Assertion failed";
+
+/// Asserts that a condition holds (equivalent to Smalltalk's `assert:`)
+/// 
+/// # Arguments
+/// * `condition` - The boolean object to check
+/// 
+/// # Returns
+/// `Ok(())` if the condition is true, otherwise an `AssertionFailed` exception
+/// 
+/// # Examples
+/// ```
+/// use smalltalkrs::core::{assert, False, True};
+/// assert!(assert(&True::new()).is_ok());
+/// assert!(assert(&False::new()).is_err());
+/// ```
+pub fn assert(condition: &dyn BooleanObject) -> Result<(), AssertionFailed> {
+    assert_description(condition, DEFAULT_ASSERTION_DESCRIPTION)
+}
+
+/// Asserts that a condition holds, with a failure description
+/// 
+/// Equivalent to Smalltalk's `assert:description:`.
+/// 
+/// # Arguments
+/// * `condition` - The boolean object to check
+/// * `description` - Explanation carried by the exception if the check fails
+/// 
+/// # Returns
+/// `Ok(())` if the condition is true, otherwise an `AssertionFailed` exception
+pub fn assert_description(
+    condition: &dyn BooleanObject,
+    description: &str,
+) -> Result<(), AssertionFailed> {
+    if condition.is_true() {
+        Ok(())
+    } else {
+        Err(AssertionFailed::new(description))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::boolean::{False, True};
+    
+    #[test]
+    fn test_true_assertion_is_a_no_op() {
+        assert!(assert(&True::new()).is_ok());
+        assert!(assert_description(&True::new(), "never raised").is_ok());
+    }
+    
+    #[test]
+    fn test_false_assertion_signals_exception() {
+        let error = assert(&False::new()).unwrap_err();
+        assert_eq!(error.message_text(), DEFAULT_ASSERTION_DESCRIPTION);
+    }
+    
+    #[test]
+    fn test_false_assertion_carries_description() {
+        let error = assert_description(&False::new(), "index out of range").unwrap_err();
+        assert_eq!(error.message_text(), "index out of range");
+    }
+    
+    #[test]
+    fn test_assertion_failure_is_catchable() {
+        fn checked_step(condition: &dyn BooleanObject) -> Result<&'static str, AssertionFailed> {
+            assert_description(condition, "precondition violated")?;
+            Ok("completed")
+        }
+        
+        // A passing assertion lets the code continue
+        assert_eq!(checked_step(&True::new()).unwrap(), "completed");
+        
+        // A failing one is propagated to the caller, which handles it
+        let handled = match checked_step(&False::new()) {
+            Ok(_) => "not caught".to_string(),
+            Err(error) => format!("caught: {}", error),
+        };
+        assert_eq!(handled, "caught: precondition violated");
+    }
+}
\ No newline at end of file
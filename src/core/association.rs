@@ -0,0 +1,209 @@
+//! Association implementation for Smalltalk
+//! 
+//! An Association is a key/value pair, normally created with the binary
+//! message `->` (e.g. `#answer -> 42`). Dictionaries store their entries
+//! as Associations.
+
+use super::object::{ObjectId, SmalltalkObject};
+use super::printing::PrintOptions;
+
+/// Association represents a key/value pair in Smalltalk
+/// 
+/// Two Associations are equal when both their keys and their values are equal.
+#[derive(Debug)]
+pub struct Association {
+    id: ObjectId,
+    key: Box<dyn SmalltalkObject>,
+    value: Box<dyn SmalltalkObject>,
+}
+
+impl Association {
+    /// Creates a new Association between a key and a value
+    /// 
+    /// Equivalent to Smalltalk's `Association key:value:`.
+    /// 
+    /// # Arguments
+    /// * `key` - The key object
+    /// * `value` - The value object
+    /// 
+    /// # Returns
+    /// A new Association object
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::{Association, SmallInteger, SmalltalkObject};
+    /// let assoc = Association::new(Box::new(SmallInteger::new(1)), Box::new(SmallInteger::new(2)));
+    /// assert_eq!(assoc.to_smalltalk_string(), "1->2");
+    /// ```
+    pub fn new(key: Box<dyn SmalltalkObject>, value: Box<dyn SmalltalkObject>) -> Self {
+        Self {
+            id: ObjectId::new(),
+            key,
+            value,
+        }
+    }
+    
+    /// Returns the key of this association
+    /// 
+    /// # Returns
+    /// A reference to the key object
+    pub fn key(&self) -> &dyn SmalltalkObject {
+        self.key.as_ref()
+    }
+    
+    /// Returns the value of this association
+    /// 
+    /// # Returns
+    /// A reference to the value object
+    pub fn value(&self) -> &dyn SmalltalkObject {
+        self.value.as_ref()
+    }
+}
+
+impl SmalltalkObject for Association {
+    fn object_id(&self) -> ObjectId {
+        self.id
+    }
+    
+    fn equals(&self, other: &dyn SmalltalkObject) -> bool {
+        // The same object is equal to itself without comparing key and value;
+        // addresses, unlike ids, can't collide across ObjectSpaces
+        if std::ptr::addr_eq(self, other) {
+            return true;
+        }
+        if let Some(other_assoc) = other.as_any().downcast_ref::<Association>() {
+            self.key.equals(other_assoc.key()) && self.value.equals(other_assoc.value())
+        } else {
+            false
+        }
+    }
+    
+    fn to_smalltalk_string(&self) -> String {
+        self.print_string_with(&PrintOptions::default())
+    }
+    
+    fn print_string_nested(&self, options: &PrintOptions, depth: usize) -> String {
+        format!(
+            "{}->{}",
+            options.print_element(self.key(), depth + 1),
+            options.print_element(self.value(), depth + 1)
+        )
+    }
+    
+    fn inspect(&self) -> Vec<(String, String)> {
+        vec![
+            ("key".to_string(), self.key.to_smalltalk_string()),
+            ("value".to_string(), self.value.to_smalltalk_string()),
+        ]
+    }
+    
+    fn child_objects(&self) -> Vec<(String, &dyn SmalltalkObject)> {
+        vec![
+            ("key".to_string(), self.key()),
+            ("value".to_string(), self.value()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::object::tests::WithId;
+    use super::super::boolean::True;
+    use super::super::small_integer::SmallInteger;
+    
+    #[test]
+    fn test_association_creation() {
+        let assoc = Association::new(Box::new(SmallInteger::new(1)), Box::new(SmallInteger::new(2)));
+        
+        assert!(assoc.key().equals(&SmallInteger::new(1)));
+        assert!(assoc.value().equals(&SmallInteger::new(2)));
+    }
+    
+    #[test]
+    fn test_arrow_creates_association() {
+        // 1 -> 2
+        let assoc = SmallInteger::new(1).associate(Box::new(SmallInteger::new(2)));
+        
+        assert!(assoc.key().equals(&SmallInteger::new(1)));
+        assert!(assoc.value().equals(&SmallInteger::new(2)));
+    }
+    
+    #[test]
+    fn test_arrow_keeps_receiver_as_key() {
+        let key = SmallInteger::new(7);
+        let key_id = key.object_id();
+        
+        let assoc = key.associate(Box::new(True::new()));
+        
+        // The receiver itself becomes the key, not a copy
+        assert_eq!(assoc.key().object_id(), key_id);
+        assert_eq!(assoc.value().to_smalltalk_string(), "true");
+    }
+    
+    #[test]
+    fn test_association_equality() {
+        let a = SmallInteger::new(1).associate(Box::new(SmallInteger::new(2)));
+        let b = SmallInteger::new(1).associate(Box::new(SmallInteger::new(2)));
+        let different_key = SmallInteger::new(3).associate(Box::new(SmallInteger::new(2)));
+        let different_value = SmallInteger::new(1).associate(Box::new(SmallInteger::new(4)));
+        
+        assert!(a.equals(&b));
+        assert!(!a.equals(&different_key));
+        assert!(!a.equals(&different_value));
+        
+        // Equal but not identical
+        assert!(!a.is_identical(&b));
+        
+        // Identical, so equal without comparing key and value
+        assert!(a.equals(&a));
+        
+        // Not equal to non-association objects
+        assert!(!a.equals(&SmallInteger::new(1)));
+    }
+    
+    #[test]
+    fn test_association_references() {
+        let key = SmallInteger::new(1);
+        let value = True::new();
+        let expected = vec![key.object_id(), value.object_id()];
+        let assoc = key.associate(Box::new(value));
+        
+        assert_eq!(assoc.references(), expected);
+    }
+    
+    #[test]
+    fn test_equals_ignores_foreign_object_with_same_id() {
+        let assoc = SmallInteger::new(1).associate(Box::new(SmallInteger::new(2)));
+        let foreign = WithId::new(assoc.object_id());
+        
+        assert!(!assoc.equals(&foreign));
+    }
+    
+    #[test]
+    fn test_association_to_string() {
+        let assoc = SmallInteger::new(1).associate(Box::new(SmallInteger::new(2)));
+        assert_eq!(assoc.to_smalltalk_string(), "1->2");
+    }
+    
+    #[test]
+    fn test_association_inspect() {
+        let assoc = SmallInteger::new(1).associate(Box::new(True::new()));
+        assert_eq!(
+            assoc.inspect(),
+            vec![
+                ("key".to_string(), "1".to_string()),
+                ("value".to_string(), "true".to_string()),
+            ]
+        );
+    }
+    
+    #[test]
+    fn test_nested_association() {
+        // 1 -> (2 -> 3)
+        let inner = SmallInteger::new(2).associate(Box::new(SmallInteger::new(3)));
+        let outer = SmallInteger::new(1).associate(Box::new(inner));
+        
+        assert_eq!(outer.to_smalltalk_string(), "1->2->3");
+    }
+}
\ No newline at end of file
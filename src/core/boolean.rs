@@ -4,6 +4,8 @@
 //! Unlike most languages, boolean logic is implemented through message passing
 //! to these objects rather than primitive operations.
 
+use std::fmt;
+
 use super::object::{ObjectId, SmalltalkObject};
 
 /// Represents the singleton True object in Smalltalk
@@ -290,6 +292,17 @@ impl BooleanObject for False {
     }
 }
 
+impl From<bool> for Box<dyn SmalltalkObject> {
+    /// Converts a Rust bool into the corresponding True or False object
+    fn from(value: bool) -> Self {
+        if value {
+            Box::new(True::new())
+        } else {
+            Box::new(False::new())
+        }
+    }
+}
+
 impl SmalltalkObject for True {
     fn object_id(&self) -> ObjectId {
         self.id
@@ -320,6 +333,18 @@ impl SmalltalkObject for False {
     }
 }
 
+impl fmt::Display for True {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.display_string())
+    }
+}
+
+impl fmt::Display for False {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.display_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -396,6 +421,17 @@ mod tests {
         assert!(false_obj.not());
     }
     
+    #[test]
+    fn test_from_bool() {
+        let true_obj: Box<dyn SmalltalkObject> = true.into();
+        let false_obj: Box<dyn SmalltalkObject> = false.into();
+        
+        assert!(true_obj.as_any().is::<True>());
+        assert!(false_obj.as_any().is::<False>());
+        assert_eq!(true_obj.to_smalltalk_string(), "true");
+        assert_eq!(false_obj.to_smalltalk_string(), "false");
+    }
+    
     #[test]
     fn test_boolean_object_trait() {
         let true_obj = True::new();
@@ -452,6 +488,12 @@ mod tests {
         assert_eq!(false_obj.to_smalltalk_string(), "false");
     }
     
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", True::new()), "true");
+        assert_eq!(format!("{}", False::new()), "false");
+    }
+    
     #[test]
     fn test_true_equality() {
         let true1 = True::new();
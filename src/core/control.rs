@@ -0,0 +1,201 @@
+//! Control flow constructs for Smalltalk
+//! 
+//! In Smalltalk, loops are not syntax but messages sent to blocks, such as
+//! `[ ... ] repeat`. This module provides those constructs for bodies written
+//! as Rust closures. A body reports whether to keep looping by returning a
+//! `ControlFlow`, which lets loops terminate early without full continuations.
+
+use std::cell::Cell;
+use std::ops::ControlFlow;
+
+/// Evaluates `body` repeatedly until it breaks
+/// 
+/// Equivalent to Smalltalk's `repeat`, which loops forever unless the body
+/// exits. Returning `ControlFlow::Break(value)` ends the loop with `value`.
+/// 
+/// # Arguments
+/// * `body` - Closure evaluated once per iteration
+/// 
+/// # Returns
+/// The value the body broke out of the loop with
+/// 
+/// # Examples
+/// ```
+/// use std::ops::ControlFlow;
+/// use smalltalkrs::core::repeat;
+/// let mut count = 0;
+/// let result = repeat(|| {
+///     count += 1;
+///     if count == 3 { ControlFlow::Break(count * 10) } else { ControlFlow::Continue(()) }
+/// });
+/// assert_eq!(result, 30);
+/// ```
+pub fn repeat<B>(mut body: impl FnMut() -> ControlFlow<B>) -> B {
+    loop {
+        if let ControlFlow::Break(value) = body() {
+            return value;
+        }
+    }
+}
+
+/// Exit block passed into the body of `value_with_exit`
+/// 
+/// Evaluating it produces the break signal that terminates the enclosing
+/// loop, and records that the exit was taken.
+#[derive(Debug, Default)]
+pub struct ExitBlock {
+    exited: Cell<bool>,
+}
+
+impl ExitBlock {
+    /// Evaluates the exit block (equivalent to Smalltalk's `exit value`)
+    /// 
+    /// Return the result from a loop body to stop the loop immediately.
+    /// 
+    /// # Returns
+    /// A `ControlFlow::Break` that terminates the loop
+    pub fn value(&self) -> ControlFlow<()> {
+        self.exited.set(true);
+        ControlFlow::Break(())
+    }
+    
+    /// Tests whether the exit block has been evaluated
+    /// 
+    /// Outer loops can check this to stop after an inner loop exited.
+    /// 
+    /// # Returns
+    /// True once `value` has been called
+    pub fn has_exited(&self) -> bool {
+        self.exited.get()
+    }
+}
+
+/// Evaluates `body` with an exit block it can use to terminate early
+/// 
+/// Equivalent to Smalltalk's `valueWithExit`, where the body receives a
+/// block that leaves it when evaluated.
+/// 
+/// # Arguments
+/// * `body` - Closure receiving the exit block
+/// 
+/// # Returns
+/// True if the body terminated through the exit block
+/// 
+/// # Examples
+/// ```
+/// use std::ops::ControlFlow;
+/// use smalltalkrs::core::{repeat, value_with_exit};
+/// let mut count = 0;
+/// let exited = value_with_exit(|exit| {
+///     repeat(|| {
+///         count += 1;
+///         if count == 5 { return exit.value(); }
+///         ControlFlow::Continue(())
+///     })
+/// });
+/// assert!(exited);
+/// assert_eq!(count, 5);
+/// ```
+pub fn value_with_exit(body: impl FnOnce(&ExitBlock)) -> bool {
+    let exit = ExitBlock::default();
+    body(&exit);
+    exit.has_exited()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_repeat_breaks_after_n_iterations() {
+        let mut iterations = 0;
+        
+        repeat(|| {
+            iterations += 1;
+            if iterations == 4 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+        
+        assert_eq!(iterations, 4);
+    }
+    
+    #[test]
+    fn test_repeat_returns_break_value() {
+        let mut n = 1;
+        
+        // Find the first power of two above 100
+        let result = repeat(|| {
+            n *= 2;
+            if n > 100 {
+                ControlFlow::Break(n)
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+        
+        assert_eq!(result, 128);
+    }
+    
+    #[test]
+    fn test_exit_block_terminates_loop_immediately() {
+        let mut visited = Vec::new();
+        
+        let exited = value_with_exit(|exit| {
+            let mut i = 0;
+            repeat(|| {
+                i += 1;
+                if i == 3 {
+                    return exit.value();
+                }
+                // Not reached on the exiting iteration
+                visited.push(i);
+                ControlFlow::Continue(())
+            })
+        });
+        
+        assert!(exited);
+        assert_eq!(visited, vec![1, 2]);
+    }
+    
+    #[test]
+    fn test_value_with_exit_without_exiting() {
+        let mut ran = false;
+        
+        let exited = value_with_exit(|_exit| {
+            ran = true;
+        });
+        
+        assert!(ran);
+        assert!(!exited);
+    }
+    
+    #[test]
+    fn test_exit_from_nested_loops() {
+        let mut outer_iterations = 0;
+        
+        value_with_exit(|exit| {
+            repeat(|| {
+                outer_iterations += 1;
+                let mut inner = 0;
+                repeat(|| {
+                    inner += 1;
+                    if inner == 2 {
+                        return exit.value();
+                    }
+                    ControlFlow::Continue(())
+                });
+                // Propagate the exit out of the outer loop
+                if exit.has_exited() {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            })
+        });
+        
+        assert_eq!(outer_iterations, 1);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,163 @@
+//! Structured debug trees of Smalltalk object graphs
+//! 
+//! Where `inspect` gives a flat list of an object's fields, a DebugNode
+//! describes the whole graph reachable from an object, which UIs can render
+//! as an expandable tree. Containers expand into their children; scalars
+//! are leaves.
+
+use super::object::{ObjectId, SmalltalkObject};
+
+/// One object in a debug tree
+/// 
+/// A node that refers back to an object already on its path from the root
+/// is marked as a cycle and not expanded again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugNode {
+    /// Class name of the object (e.g. `SmallInteger`)
+    pub class_name: String,
+    /// Identity of the object
+    pub id: ObjectId,
+    /// The object's print string with nested elements elided, since the
+    /// children carry their own labels
+    pub print_string: String,
+    /// Child nodes labelled by the field or index they were reached through
+    pub children: Vec<(String, DebugNode)>,
+    /// True if this object is one of its own ancestors in the tree
+    pub is_cycle: bool,
+}
+
+impl DebugNode {
+    /// Tests whether this node has no children
+    /// 
+    /// # Returns
+    /// True for scalars, empty containers, and cycle markers
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+    
+    /// Returns the child reached through the given label
+    /// 
+    /// # Arguments
+    /// * `label` - Field name or index of the child
+    /// 
+    /// # Returns
+    /// The child node, or None if there is no such child
+    pub fn child(&self, label: &str) -> Option<&DebugNode> {
+        self.children
+            .iter()
+            .find(|(child_label, _)| child_label == label)
+            .map(|(_, node)| node)
+    }
+}
+
+/// Builds the node for an object whose ancestors' ids are on `path`
+/// 
+/// Used by the default `SmalltalkObject::debug_tree_on_path`. The print
+/// string is only computed for objects that aren't cycles.
+pub(crate) fn build_node(
+    id: ObjectId,
+    class_name: &str,
+    print_string: impl FnOnce() -> String,
+    child_objects: Vec<(String, &dyn SmalltalkObject)>,
+    path: &mut Vec<ObjectId>,
+) -> DebugNode {
+    let is_cycle = path.contains(&id);
+    let mut children = Vec::new();
+    
+    if !is_cycle {
+        path.push(id);
+        for (label, child) in child_objects {
+            children.push((label, child.debug_tree_on_path(path)));
+        }
+        path.pop();
+    }
+    
+    DebugNode {
+        class_name: class_name.to_string(),
+        id,
+        print_string: if is_cycle {
+            "(...)".to_string()
+        } else {
+            print_string()
+        },
+        children,
+        is_cycle,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::boolean::True;
+    use super::super::object::tests::SelfReferencing;
+    use super::super::ring_buffer::tests::nested_buffers;
+    use super::super::ring_buffer::RingBuffer;
+    use super::super::small_integer::SmallInteger;
+    
+    #[test]
+    fn test_scalar_is_leaf() {
+        let num = SmallInteger::new(42);
+        let tree = num.debug_tree();
+        
+        assert_eq!(tree.class_name, "SmallInteger");
+        assert_eq!(tree.id, num.object_id());
+        assert_eq!(tree.print_string, "42");
+        assert!(tree.is_leaf());
+        assert!(!tree.is_cycle);
+    }
+    
+    #[test]
+    fn test_nested_structure_shape() {
+        // A RingBuffer holding 1 and (#key -> (true -> 2))
+        let inner = True::new().associate(Box::new(SmallInteger::new(2)));
+        let outer = SmallInteger::new(7).associate(Box::new(inner));
+        let mut buffer = RingBuffer::new(2);
+        buffer.add(Box::new(SmallInteger::new(1)));
+        buffer.add(Box::new(outer));
+        
+        let tree = buffer.debug_tree();
+        
+        assert_eq!(tree.class_name, "RingBuffer");
+        assert_eq!(tree.children.len(), 2);
+        assert_eq!(tree.children[0].0, "1");
+        assert_eq!(tree.children[1].0, "2");
+        
+        let first = tree.child("1").unwrap();
+        assert_eq!(first.class_name, "SmallInteger");
+        assert!(first.is_leaf());
+        
+        let association = tree.child("2").unwrap();
+        assert_eq!(association.class_name, "Association");
+        assert_eq!(association.print_string, "...->...");
+        assert_eq!(association.child("key").unwrap().print_string, "7");
+        
+        let nested = association.child("value").unwrap();
+        assert_eq!(nested.class_name, "Association");
+        assert_eq!(nested.child("key").unwrap().class_name, "True");
+        assert_eq!(nested.child("value").unwrap().print_string, "2");
+        assert!(nested.child("missing").is_none());
+    }
+    
+    #[test]
+    fn test_container_print_string_is_bounded() {
+        let nested = nested_buffers(50);
+        let tree = nested.debug_tree();
+        
+        assert_eq!(tree.print_string, "a RingBuffer(...)");
+        assert_eq!(tree.child("1").unwrap().print_string, "a RingBuffer(...)");
+    }
+    
+    #[test]
+    fn test_self_reference_marks_cycle() {
+        let obj = SelfReferencing::new();
+        let tree = obj.debug_tree();
+        
+        assert!(!tree.is_cycle);
+        assert_eq!(tree.children.len(), 2);
+        let child = tree.child("self").unwrap();
+        assert!(child.is_cycle);
+        assert_eq!(child.id, obj.object_id());
+        assert_eq!(child.print_string, "(...)");
+        assert!(child.is_leaf());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,341 @@
+//! Exception objects for Smalltalk
+//! 
+//! In Smalltalk, error conditions are reported by signaling exception objects
+//! (e.g. `ZeroDivide`) which can be caught by a handler further up the stack.
+//! Until handler blocks are available, signaling is modelled by returning the
+//! exception in the `Err` variant of a `Result`, so callers "catch" it by
+//! matching on the result.
+
+use std::fmt;
+
+use super::object::{ObjectId, SmalltalkObject};
+
+/// Signaled when a number is divided by zero
+/// 
+/// Equivalent to Smalltalk's `ZeroDivide` exception. It records the dividend
+/// so handlers can inspect what was being divided.
+#[derive(Debug, Clone)]
+pub struct ZeroDivide {
+    id: ObjectId,
+    dividend: i64,
+}
+
+impl ZeroDivide {
+    /// Creates a new ZeroDivide exception
+    /// 
+    /// # Arguments
+    /// * `dividend` - The value that was being divided by zero
+    /// 
+    /// # Returns
+    /// A new ZeroDivide exception object
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::ZeroDivide;
+    /// let error = ZeroDivide::new(7);
+    /// assert_eq!(error.dividend(), 7);
+    /// ```
+    pub fn new(dividend: i64) -> Self {
+        Self {
+            id: ObjectId::new(),
+            dividend,
+        }
+    }
+    
+    /// Returns the value that was being divided
+    /// 
+    /// # Returns
+    /// The dividend of the failed division
+    pub fn dividend(&self) -> i64 {
+        self.dividend
+    }
+    
+    /// Returns a description of the error (equivalent to Smalltalk's `messageText`)
+    /// 
+    /// # Returns
+    /// A human readable message describing the failed division
+    pub fn message_text(&self) -> String {
+        format!("Attempt to divide {} by zero", self.dividend)
+    }
+}
+
+impl SmalltalkObject for ZeroDivide {
+    fn object_id(&self) -> ObjectId {
+        self.id
+    }
+    
+    fn to_smalltalk_string(&self) -> String {
+        format!("ZeroDivide: {}", self.message_text())
+    }
+    
+    fn inspect(&self) -> Vec<(String, String)> {
+        vec![
+            ("dividend".to_string(), self.dividend.to_string()),
+            ("messageText".to_string(), format!("'{}'", self.message_text())),
+        ]
+    }
+}
+
+impl fmt::Display for ZeroDivide {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message_text())
+    }
+}
+
+impl std::error::Error for ZeroDivide {}
+
+/// Signaled when an arithmetic operation has no valid result
+/// 
+/// Equivalent to Smalltalk's `ArithmeticError`, e.g. when a result does not
+/// fit in a SmallInteger or an argument is outside the operation's domain.
+#[derive(Debug, Clone)]
+pub struct ArithmeticError {
+    id: ObjectId,
+    description: String,
+}
+
+impl ArithmeticError {
+    /// Creates a new ArithmeticError exception
+    /// 
+    /// # Arguments
+    /// * `description` - Explanation of why the operation failed
+    /// 
+    /// # Returns
+    /// A new ArithmeticError exception object
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::ArithmeticError;
+    /// let error = ArithmeticError::new("result out of range");
+    /// assert_eq!(error.message_text(), "result out of range");
+    /// ```
+    pub fn new(description: &str) -> Self {
+        Self {
+            id: ObjectId::new(),
+            description: description.to_string(),
+        }
+    }
+    
+    /// Returns a description of the error (equivalent to Smalltalk's `messageText`)
+    /// 
+    /// # Returns
+    /// The description given when the error was signaled
+    pub fn message_text(&self) -> &str {
+        &self.description
+    }
+}
+
+impl SmalltalkObject for ArithmeticError {
+    fn object_id(&self) -> ObjectId {
+        self.id
+    }
+    
+    fn to_smalltalk_string(&self) -> String {
+        format!("ArithmeticError: {}", self.description)
+    }
+    
+    fn inspect(&self) -> Vec<(String, String)> {
+        vec![("messageText".to_string(), format!("'{}'", self.description))]
+    }
+}
+
+impl fmt::Display for ArithmeticError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.description)
+    }
+}
+
+impl std::error::Error for ArithmeticError {}
+
+/// Signaled by integer division that can fail in more than one way
+/// 
+/// In Smalltalk, `ZeroDivide` is a kind of `ArithmeticError`. Operations such
+/// as `//` can signal either one, so they return this enum, which keeps the
+/// specific exception available to callers that match on it.
+#[derive(Debug, Clone)]
+pub enum DivisionError {
+    /// The divisor was zero
+    ZeroDivide(ZeroDivide),
+    /// The result does not fit in a SmallInteger
+    Overflow(ArithmeticError),
+}
+
+impl DivisionError {
+    /// Returns a description of the error (equivalent to Smalltalk's `messageText`)
+    /// 
+    /// # Returns
+    /// The message text of the wrapped exception
+    pub fn message_text(&self) -> String {
+        match self {
+            DivisionError::ZeroDivide(error) => error.message_text(),
+            DivisionError::Overflow(error) => error.message_text().to_string(),
+        }
+    }
+}
+
+impl From<ZeroDivide> for DivisionError {
+    fn from(error: ZeroDivide) -> Self {
+        DivisionError::ZeroDivide(error)
+    }
+}
+
+impl From<ArithmeticError> for DivisionError {
+    fn from(error: ArithmeticError) -> Self {
+        DivisionError::Overflow(error)
+    }
+}
+
+impl fmt::Display for DivisionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message_text())
+    }
+}
+
+impl std::error::Error for DivisionError {}
+
+/// Signaled when an assertion does not hold
+/// 
+/// Equivalent to Smalltalk's `AssertionFailure`, raised by `assert:` and
+/// `assert:description:` when their condition is false.
+#[derive(Debug, Clone)]
+pub struct AssertionFailed {
+    id: ObjectId,
+    description: String,
+}
+
+impl AssertionFailed {
+    /// Creates a new AssertionFailed exception
+    /// 
+    /// # Arguments
+    /// * `description` - Explanation of the assertion that failed
+    /// 
+    /// # Returns
+    /// A new AssertionFailed exception object
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::AssertionFailed;
+    /// let error = AssertionFailed::new("list must be sorted");
+    /// assert_eq!(error.message_text(), "list must be sorted");
+    /// ```
+    pub fn new(description: &str) -> Self {
+        Self {
+            id: ObjectId::new(),
+            description: description.to_string(),
+        }
+    }
+    
+    /// Returns a description of the error (equivalent to Smalltalk's `messageText`)
+    /// 
+    /// # Returns
+    /// The description given when the assertion failed
+    pub fn message_text(&self) -> &str {
+        &self.description
+    }
+}
+
+impl SmalltalkObject for AssertionFailed {
+    fn object_id(&self) -> ObjectId {
+        self.id
+    }
+    
+    fn to_smalltalk_string(&self) -> String {
+        format!("AssertionFailure: {}", self.description)
+    }
+    
+    fn inspect(&self) -> Vec<(String, String)> {
+        vec![("messageText".to_string(), format!("'{}'", self.description))]
+    }
+}
+
+impl fmt::Display for AssertionFailed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.description)
+    }
+}
+
+impl std::error::Error for AssertionFailed {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_zero_divide_creation() {
+        let error = ZeroDivide::new(42);
+        assert_eq!(error.dividend(), 42);
+    }
+    
+    #[test]
+    fn test_zero_divide_message_text() {
+        let error = ZeroDivide::new(42);
+        assert_eq!(error.message_text(), "Attempt to divide 42 by zero");
+        assert_eq!(format!("{}", error), error.message_text());
+    }
+    
+    #[test]
+    fn test_zero_divide_to_string() {
+        let error = ZeroDivide::new(-3);
+        assert_eq!(error.to_smalltalk_string(), "ZeroDivide: Attempt to divide -3 by zero");
+    }
+    
+    #[test]
+    fn test_zero_divide_inspect() {
+        let error = ZeroDivide::new(5);
+        assert_eq!(
+            error.inspect(),
+            vec![
+                ("dividend".to_string(), "5".to_string()),
+                ("messageText".to_string(), "'Attempt to divide 5 by zero'".to_string()),
+            ]
+        );
+    }
+    
+    #[test]
+    fn test_zero_divide_unique_ids() {
+        let error1 = ZeroDivide::new(1);
+        let error2 = ZeroDivide::new(1);
+        
+        // Each signal creates a distinct exception object
+        assert!(!error1.is_identical(&error2));
+    }
+    
+    #[test]
+    fn test_arithmetic_error_message_text() {
+        let error = ArithmeticError::new("result out of range");
+        
+        assert_eq!(error.message_text(), "result out of range");
+        assert_eq!(format!("{}", error), "result out of range");
+        assert_eq!(error.to_smalltalk_string(), "ArithmeticError: result out of range");
+        assert_eq!(
+            error.inspect(),
+            vec![("messageText".to_string(), "'result out of range'".to_string())]
+        );
+    }
+    
+    #[test]
+    fn test_division_error_wraps_exceptions() {
+        let zero_divide = DivisionError::from(ZeroDivide::new(4));
+        assert!(matches!(&zero_divide, DivisionError::ZeroDivide(e) if e.dividend() == 4));
+        assert_eq!(zero_divide.message_text(), "Attempt to divide 4 by zero");
+        
+        let overflow = DivisionError::from(ArithmeticError::new("too big"));
+        assert!(matches!(overflow, DivisionError::Overflow(_)));
+        assert_eq!(format!("{}", overflow), "too big");
+    }
+    
+    #[test]
+    fn test_assertion_failed_message_text() {
+        let error = AssertionFailed::new("value must be positive");
+        
+        assert_eq!(error.message_text(), "value must be positive");
+        assert_eq!(format!("{}", error), "value must be positive");
+        assert_eq!(error.to_smalltalk_string(), "AssertionFailure: value must be positive");
+    }
+    
+    #[test]
+    fn test_assertion_failed_inspect() {
+        let error = AssertionFailed::new("oops");
+        assert_eq!(error.inspect(), vec![("messageText".to_string(), "'oops'".to_string())]);
+    }
+}
\ No newline at end of file
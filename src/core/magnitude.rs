@@ -0,0 +1,102 @@
+//! Magnitude protocol for Smalltalk
+//! 
+//! In Smalltalk, Magnitude is the abstract superclass of objects that can be
+//! compared and ordered, such as numbers, characters, and dates. Subclasses
+//! only need to define `<`; the remaining comparisons are derived from it.
+
+/// Common trait for objects that can be compared and ordered
+/// 
+/// This trait corresponds to Smalltalk's Magnitude class. Implementors provide
+/// `less_than` and get the derived comparisons for free.
+pub trait Magnitude {
+    /// Tests if this magnitude is less than another (equivalent to Smalltalk's `<`)
+    /// 
+    /// # Arguments
+    /// * `other` - The magnitude to compare with
+    /// 
+    /// # Returns
+    /// True if this magnitude is less than the other
+    fn less_than(&self, other: &Self) -> bool;
+    
+    /// Tests if this magnitude is greater than another (equivalent to Smalltalk's `>`)
+    /// 
+    /// # Arguments
+    /// * `other` - The magnitude to compare with
+    /// 
+    /// # Returns
+    /// True if this magnitude is greater than the other
+    fn greater_than(&self, other: &Self) -> bool {
+        other.less_than(self)
+    }
+    
+    /// Constrains this magnitude to the range `low`..`high`
+    /// 
+    /// Equivalent to Smalltalk's `clampLow:high:`. Returns `low` if the receiver
+    /// is below the range, `high` if it is above, and the receiver otherwise.
+    /// 
+    /// # Arguments
+    /// * `low` - The lower bound of the range
+    /// * `high` - The upper bound of the range
+    /// 
+    /// # Returns
+    /// A copy of whichever of the receiver, `low` or `high` lies in range
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::{Magnitude, SmallInteger};
+    /// let low = SmallInteger::new(0);
+    /// let high = SmallInteger::new(10);
+    /// assert_eq!(SmallInteger::new(15).clamp(&low, &high).value(), 10);
+    /// ```
+    fn clamp(&self, low: &Self, high: &Self) -> Self
+    where
+        Self: Clone,
+    {
+        if self.less_than(low) {
+            low.clone()
+        } else if self.greater_than(high) {
+            high.clone()
+        } else {
+            self.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Minimal magnitude used to exercise the default implementations
+    #[derive(Debug, Clone, PartialEq)]
+    struct TestMagnitude(i32);
+    
+    impl Magnitude for TestMagnitude {
+        fn less_than(&self, other: &Self) -> bool {
+            self.0 < other.0
+        }
+    }
+    
+    #[test]
+    fn test_greater_than_derived_from_less_than() {
+        let a = TestMagnitude(3);
+        let b = TestMagnitude(5);
+        
+        assert!(b.greater_than(&a));
+        assert!(!a.greater_than(&b));
+        assert!(!a.greater_than(&a.clone())); // Equal values
+    }
+    
+    #[test]
+    fn test_clamp() {
+        let low = TestMagnitude(0);
+        let high = TestMagnitude(10);
+        
+        assert_eq!(TestMagnitude(-5).clamp(&low, &high), low);
+        assert_eq!(TestMagnitude(15).clamp(&low, &high), high);
+        assert_eq!(TestMagnitude(5).clamp(&low, &high), TestMagnitude(5));
+        
+        // Bounds themselves are in range
+        assert_eq!(TestMagnitude(0).clamp(&low, &high), low);
+        assert_eq!(TestMagnitude(10).clamp(&low, &high), high);
+    }
+}
\ No newline at end of file
@@ -4,9 +4,31 @@
 //! including the base Object trait and object identity management.
 
 pub mod object;
+pub mod association;
+pub mod assertions;
 pub mod small_integer;
 pub mod boolean;
+pub mod control;
+pub mod debug_tree;
+pub mod exception;
+pub mod magnitude;
+pub mod printing;
+pub mod process;
+pub mod ring_buffer;
+pub mod transcript;
+pub mod visitor;
 
 pub use object::*;
+pub use association::*;
+pub use assertions::*;
 pub use small_integer::*;
-pub use boolean::*;
\ No newline at end of file
+pub use boolean::*;
+pub use control::*;
+pub use debug_tree::*;
+pub use exception::*;
+pub use magnitude::*;
+pub use printing::*;
+pub use process::*;
+pub use ring_buffer::*;
+pub use transcript::*;
+pub use visitor::*;
\ No newline at end of file
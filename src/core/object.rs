@@ -5,7 +5,17 @@
 //! operations for object identity, equality, and string representation.
 
 use std::any::Any;
+use std::collections::HashSet;
 use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::association::Association;
+use super::boolean::{False, True};
+use super::debug_tree::{self, DebugNode};
+use super::printing::PrintOptions;
+use super::small_integer::SmallInteger;
+use super::transcript::Transcript;
+use super::visitor::{self, Visitor};
 
 /// Unique identifier for each object instance in the Smalltalk system
 /// 
@@ -28,9 +38,7 @@ impl ObjectId {
     /// assert_ne!(id1, id2);
     /// ```
     pub fn new() -> Self {
-        use std::sync::atomic::{AtomicU64, Ordering};
-        static COUNTER: AtomicU64 = AtomicU64::new(1);
-        ObjectId(COUNTER.fetch_add(1, Ordering::Relaxed))
+        ObjectSpace::global().new_id()
     }
     
     /// Returns the raw ID value
@@ -48,6 +56,70 @@ impl fmt::Display for ObjectId {
     }
 }
 
+/// Allocator of ObjectIds for an independent object world
+/// 
+/// Each ObjectSpace has its own id counter starting at 1, so separate worlds
+/// (e.g. sandboxed tests) get deterministic ids without affecting each other.
+/// `ObjectId::new` allocates from the process-wide global space. Ids are only
+/// unique within the space that allocated them.
+#[derive(Debug)]
+pub struct ObjectSpace {
+    next_id: AtomicU64,
+}
+
+impl ObjectSpace {
+    /// Creates a new ObjectSpace whose first id is 1
+    /// 
+    /// # Returns
+    /// A new ObjectSpace with a fresh id counter
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::ObjectSpace;
+    /// let space = ObjectSpace::new();
+    /// assert_eq!(space.new_id().value(), 1);
+    /// assert_eq!(space.new_id().value(), 2);
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            next_id: AtomicU64::new(1),
+        }
+    }
+    
+    /// Returns the process-wide space used by `ObjectId::new`
+    /// 
+    /// # Returns
+    /// A reference to the global ObjectSpace
+    pub fn global() -> &'static ObjectSpace {
+        static GLOBAL: ObjectSpace = ObjectSpace::new();
+        &GLOBAL
+    }
+    
+    /// Allocates the next ObjectId in this space
+    /// 
+    /// # Returns
+    /// A new ObjectId unique within this space
+    pub fn new_id(&self) -> ObjectId {
+        ObjectId(self.next_id.fetch_add(1, Ordering::Relaxed))
+    }
+    
+    /// Resets the id counter so the next allocated id is 1
+    /// 
+    /// Only available in tests, to make id-dependent assertions reproducible.
+    /// Use it on a test-local space: resetting the global space would hand out
+    /// duplicate ids to other tests running in parallel.
+    #[cfg(test)]
+    pub fn reset_for_testing(&self) {
+        self.next_id.store(1, Ordering::Relaxed);
+    }
+}
+
+impl Default for ObjectSpace {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Base trait that all Smalltalk objects must implement
 /// 
 /// This trait provides the fundamental operations that every object in the
@@ -104,6 +176,26 @@ pub trait SmalltalkObject: Any + fmt::Debug + Send + Sync {
         self.is_identical(other)
     }
     
+    /// Returns the name of this object's class
+    /// 
+    /// Equivalent to Smalltalk's `class name`. Default implementation uses
+    /// the Rust type name without its module path or generic arguments,
+    /// e.g. `SmallInteger` rather than `smalltalkrs::core::...::SmallInteger`.
+    /// 
+    /// # Returns
+    /// The class name
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::{SmallInteger, SmalltalkObject};
+    /// assert_eq!(SmallInteger::new(1).class_name(), "SmallInteger");
+    /// ```
+    fn class_name(&self) -> &'static str {
+        let type_name = std::any::type_name::<Self>();
+        let path = type_name.split('<').next().unwrap_or(type_name);
+        path.rsplit("::").next().unwrap_or(path)
+    }
+    
     /// Returns a string representation of this object
     /// 
     /// Equivalent to Smalltalk's `printString` method.
@@ -112,7 +204,286 @@ pub trait SmalltalkObject: Any + fmt::Debug + Send + Sync {
     /// # Returns
     /// A string describing this object
     fn to_smalltalk_string(&self) -> String {
-        format!("a {} {}", std::any::type_name::<Self>(), self.object_id())
+        format!("a {} {}", self.class_name(), self.object_id())
+    }
+    
+    /// Returns a string representation bounded by the given options
+    /// 
+    /// Used by inspectors to keep output manageable for large or deeply
+    /// nested objects. With default options this is the same as
+    /// `to_smalltalk_string`. Containers customize nesting through
+    /// `print_string_nested` rather than overriding this method.
+    /// 
+    /// # Arguments
+    /// * `options` - Limits on depth, elements per collection, and length
+    /// 
+    /// # Returns
+    /// A string describing this object within the given limits
+    fn print_string_with(&self, options: &PrintOptions) -> String {
+        options.limit_length(self.print_string_nested(options, 0))
+    }
+    
+    /// Returns a bounded string representation of this object at a nesting depth
+    /// 
+    /// Containers override this to print their elements through
+    /// `PrintOptions::print_element` or `print_elements` at `depth + 1`.
+    /// Default implementation treats the object as a leaf and returns
+    /// `to_smalltalk_string`.
+    /// 
+    /// # Arguments
+    /// * `_options` - Limits on depth and elements per collection
+    /// * `_depth` - Nesting depth of this object; the printed receiver is at 0
+    /// 
+    /// # Returns
+    /// A string describing this object within the given limits
+    fn print_string_nested(&self, _options: &PrintOptions, _depth: usize) -> String {
+        self.to_smalltalk_string()
+    }
+    
+    /// Returns a string for displaying this object to users
+    /// 
+    /// Equivalent to Smalltalk's `displayString`. It differs from the print
+    /// string only for objects like strings and symbols, which display
+    /// without their literal quoting. Default implementation returns
+    /// `to_smalltalk_string`.
+    /// 
+    /// # Returns
+    /// A user-facing string describing this object
+    fn display_string(&self) -> String {
+        self.to_smalltalk_string()
+    }
+    
+    /// Writes the print string and a newline to the Transcript (equivalent to Smalltalk's `printNl`)
+    /// 
+    /// # Returns
+    /// This object, so further messages can be chained
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::{SmallInteger, SmalltalkObject};
+    /// let num = SmallInteger::new(5);
+    /// assert!(num.print_nl().is_identical(&num)); // Prints "5"
+    /// ```
+    fn print_nl(&self) -> &dyn SmalltalkObject
+    where
+        Self: Sized,
+    {
+        Transcript::current().show(&self.to_smalltalk_string()).cr();
+        self
+    }
+    
+    /// Writes the display string and a newline to the Transcript (equivalent to Smalltalk's `displayNl`)
+    /// 
+    /// # Returns
+    /// This object, so further messages can be chained
+    fn display_nl(&self) -> &dyn SmalltalkObject
+    where
+        Self: Sized,
+    {
+        Transcript::current().show(&self.display_string()).cr();
+        self
+    }
+    
+    /// Returns the named fields of this object and their print strings
+    /// 
+    /// Used by inspector tools to display an object's internal state.
+    /// Default implementation lists the class name and object ID; types
+    /// with state should override it to list their own fields.
+    /// 
+    /// # Returns
+    /// A list of (field name, print string) pairs
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::{SmallInteger, SmalltalkObject};
+    /// let num = SmallInteger::new(42);
+    /// assert_eq!(num.inspect(), vec![("value".to_string(), "42".to_string())]);
+    /// ```
+    fn inspect(&self) -> Vec<(String, String)> {
+        vec![
+            ("class".to_string(), self.class_name().to_string()),
+            ("id".to_string(), self.object_id().to_string()),
+        ]
+    }
+    
+    /// Returns the objects directly contained in this object
+    /// 
+    /// Containers override this to expose their elements, labelled by field
+    /// name or 1-based index, so graph tools can walk into them. Default
+    /// implementation returns no children, treating the object as a scalar.
+    /// 
+    /// # Returns
+    /// A list of (label, child object) pairs
+    fn child_objects(&self) -> Vec<(String, &dyn SmalltalkObject)> {
+        Vec::new()
+    }
+    
+    /// Returns the ids of the objects this object directly references
+    /// 
+    /// This is the id-only view of `child_objects`, for graph traversals
+    /// such as garbage collection that work with identities rather than
+    /// objects. Scalars reference nothing.
+    /// 
+    /// # Returns
+    /// The object ids of this object's children, in `child_objects` order
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::{SmallInteger, SmalltalkObject};
+    /// let value = SmallInteger::new(2);
+    /// let value_id = value.object_id();
+    /// let pair = SmallInteger::new(1).associate(Box::new(value));
+    /// assert_eq!(pair.references()[1], value_id);
+    /// ```
+    fn references(&self) -> Vec<ObjectId> {
+        self.child_objects()
+            .into_iter()
+            .map(|(_, child)| child.object_id())
+            .collect()
+    }
+    
+    /// Returns a structured tree describing the object graph from this object
+    /// 
+    /// Each node records the class name, id, and print string of an object,
+    /// with its `child_objects` as children. Objects that are their own
+    /// ancestors are marked as cycles instead of being expanded again.
+    /// 
+    /// # Returns
+    /// The root DebugNode for this object
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::{SmallInteger, SmalltalkObject};
+    /// let tree = SmallInteger::new(42).debug_tree();
+    /// assert_eq!(tree.class_name, "SmallInteger");
+    /// assert!(tree.is_leaf());
+    /// ```
+    fn debug_tree(&self) -> DebugNode {
+        self.debug_tree_on_path(&mut Vec::new())
+    }
+    
+    /// Builds the debug tree for this object below the given ancestors
+    /// 
+    /// Used by `debug_tree` to recurse into children; `path` holds the ids
+    /// of the objects between the root and this one. Implementors don't
+    /// need to override it.
+    /// 
+    /// # Arguments
+    /// * `path` - Ids of this object's ancestors, root first
+    /// 
+    /// # Returns
+    /// The DebugNode for this object
+    fn debug_tree_on_path(&self, path: &mut Vec<ObjectId>) -> DebugNode {
+        debug_tree::build_node(
+            self.object_id(),
+            self.class_name(),
+            || {
+                self.print_string_with(&PrintOptions {
+                    max_depth: Some(0),
+                    ..PrintOptions::default()
+                })
+            },
+            self.child_objects(),
+            path,
+        )
+    }
+    
+    /// Returns the approximate memory used by this object alone
+    /// 
+    /// Default implementation returns the size of the object's struct.
+    /// Objects owning heap storage of their own (other than child objects)
+    /// override this to add it.
+    /// 
+    /// # Returns
+    /// The object's footprint in bytes, excluding its child objects
+    fn shallow_size(&self) -> usize {
+        std::mem::size_of_val(self)
+    }
+    
+    /// Returns the approximate memory used by the object graph from this object
+    /// 
+    /// Sums `shallow_size` over this object and everything reachable through
+    /// `child_objects`, deduplicated by object id as in `visitor::walk`.
+    /// 
+    /// # Returns
+    /// The graph's footprint in bytes
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::{SmallInteger, SmalltalkObject};
+    /// let pair = SmallInteger::new(3).associate(Box::new(SmallInteger::new(4)));
+    /// assert!(pair.deep_size() > pair.shallow_size());
+    /// ```
+    fn deep_size(&self) -> usize {
+        /// Sums the shallow sizes of the objects it visits
+        struct Footprint(usize);
+        
+        impl Visitor for Footprint {
+            fn visit_object(&mut self, object: &dyn SmalltalkObject) {
+                self.0 += object.shallow_size();
+            }
+        }
+        
+        let mut footprint = Footprint(self.shallow_size());
+        let mut visited = HashSet::from([self.object_id()]);
+        for (_, child) in self.child_objects() {
+            visitor::walk(child, &mut footprint, &mut visited);
+        }
+        footprint.0
+    }
+    
+    /// Walks the object graph from this object, calling back a visitor
+    /// 
+    /// Each reachable object (this one and, recursively, its
+    /// `child_objects`) is passed to the visitor callback for its class,
+    /// parents before children, once per object id (see `visitor::walk`).
+    /// Trait objects use the equivalent `accept_dyn`.
+    /// 
+    /// # Arguments
+    /// * `visitor` - The Visitor receiving the callbacks
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::{SmallInteger, SmalltalkObject, Visitor};
+    /// struct Sum(i64);
+    /// impl Visitor for Sum {
+    ///     fn visit_small_integer(&mut self, integer: &SmallInteger) {
+    ///         self.0 += integer.value();
+    ///     }
+    /// }
+    /// let pair = SmallInteger::new(3).associate(Box::new(SmallInteger::new(4)));
+    /// let mut sum = Sum(0);
+    /// pair.accept(&mut sum);
+    /// assert_eq!(sum.0, 7);
+    /// ```
+    fn accept(&self, visitor: &mut dyn Visitor)
+    where
+        Self: Sized,
+    {
+        visitor::walk(self, visitor, &mut HashSet::new());
+    }
+    
+    /// Creates an Association with this object as key (equivalent to Smalltalk's `->`)
+    /// 
+    /// Used to build dictionary entries inline, e.g. `1 -> 2`.
+    /// 
+    /// # Arguments
+    /// * `value` - The object to associate with this key
+    /// 
+    /// # Returns
+    /// A new Association whose key is this object
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::{SmallInteger, SmalltalkObject};
+    /// let assoc = SmallInteger::new(1).associate(Box::new(SmallInteger::new(2)));
+    /// assert_eq!(assoc.to_smalltalk_string(), "1->2");
+    /// ```
+    fn associate(self, value: Box<dyn SmalltalkObject>) -> Association
+    where
+        Self: Sized,
+    {
+        Association::new(Box::new(self), value)
     }
 }
 
@@ -128,10 +499,98 @@ impl dyn SmalltalkObject {
     pub fn as_any(&self) -> &dyn Any {
         self
     }
+    
+    /// Extracts the integer value if this object is a SmallInteger
+    /// 
+    /// # Returns
+    /// The wrapped i64, or None for any other kind of object
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::{SmallInteger, SmalltalkObject};
+    /// let obj: Box<dyn SmalltalkObject> = Box::new(SmallInteger::new(42));
+    /// assert_eq!(obj.as_i64(), Some(42));
+    /// ```
+    pub fn as_i64(&self) -> Option<i64> {
+        self.as_any().downcast_ref::<SmallInteger>().map(SmallInteger::value)
+    }
+    
+    /// Extracts the boolean value if this object is True or False
+    /// 
+    /// # Returns
+    /// The corresponding bool, or None for any other kind of object
+    pub fn as_bool(&self) -> Option<bool> {
+        if self.as_any().is::<True>() {
+            Some(true)
+        } else if self.as_any().is::<False>() {
+            Some(false)
+        } else {
+            None
+        }
+    }
+    
+    /// Writes the print string and a newline to the Transcript, for trait objects
+    /// 
+    /// Same as `SmalltalkObject::print_nl`, which requires `Self: Sized`,
+    /// for objects only known as `dyn SmalltalkObject` (such as collection
+    /// elements).
+    /// 
+    /// # Returns
+    /// This object, so further messages can be chained
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::{SmallInteger, SmalltalkObject};
+    /// let obj: Box<dyn SmalltalkObject> = Box::new(SmallInteger::new(5));
+    /// obj.print_nl_dyn().print_nl_dyn(); // Prints "5" twice
+    /// ```
+    pub fn print_nl_dyn(&self) -> &dyn SmalltalkObject {
+        Transcript::current().show(&self.to_smalltalk_string()).cr();
+        self
+    }
+    
+    /// Writes the display string and a newline to the Transcript, for trait objects
+    /// 
+    /// Same as `SmalltalkObject::display_nl`, for objects only known as
+    /// `dyn SmalltalkObject`.
+    /// 
+    /// # Returns
+    /// This object, so further messages can be chained
+    pub fn display_nl_dyn(&self) -> &dyn SmalltalkObject {
+        Transcript::current().show(&self.display_string()).cr();
+        self
+    }
+    
+    /// Walks the object graph from a trait object, calling back a visitor
+    /// 
+    /// Same traversal as `SmalltalkObject::accept`, for objects only known
+    /// as `dyn SmalltalkObject` (such as collection elements), where the
+    /// trait method isn't callable because it requires `Self: Sized`.
+    /// 
+    /// # Arguments
+    /// * `visitor` - The Visitor receiving the callbacks
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::{SmallInteger, SmalltalkObject, Visitor};
+    /// struct Count(usize);
+    /// impl Visitor for Count {
+    ///     fn visit_object(&mut self, _object: &dyn SmalltalkObject) {
+    ///         self.0 += 1;
+    ///     }
+    /// }
+    /// let obj: Box<dyn SmalltalkObject> = Box::new(SmallInteger::new(3));
+    /// let mut count = Count(0);
+    /// obj.accept_dyn(&mut count);
+    /// assert_eq!(count.0, 1);
+    /// ```
+    pub fn accept_dyn<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        visitor::walk(self, visitor, &mut HashSet::new());
+    }
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
     
     /// Test implementation of SmalltalkObject for testing purposes
@@ -168,6 +627,48 @@ mod tests {
         }
     }
     
+    /// Plain object carrying a given id, e.g. one handed out by another ObjectSpace
+    #[derive(Debug)]
+    pub(crate) struct WithId {
+        id: ObjectId,
+    }
+    
+    impl WithId {
+        pub(crate) fn new(id: ObjectId) -> Self {
+            Self { id }
+        }
+    }
+    
+    impl SmalltalkObject for WithId {
+        fn object_id(&self) -> ObjectId {
+            self.id
+        }
+    }
+    
+    /// Object that lists itself as a child (twice), like a collection containing itself
+    /// 
+    /// Owned children can't form cycles, so graph walkers are tested on this.
+    #[derive(Debug)]
+    pub(crate) struct SelfReferencing {
+        id: ObjectId,
+    }
+    
+    impl SelfReferencing {
+        pub(crate) fn new() -> Self {
+            Self { id: ObjectId::new() }
+        }
+    }
+    
+    impl SmalltalkObject for SelfReferencing {
+        fn object_id(&self) -> ObjectId {
+            self.id
+        }
+        
+        fn child_objects(&self) -> Vec<(String, &dyn SmalltalkObject)> {
+            vec![("self".to_string(), self), ("again".to_string(), self)]
+        }
+    }
+    
     
     #[test]
     fn test_object_id_uniqueness() {
@@ -188,6 +689,56 @@ mod tests {
         assert_eq!(display, format!("#{}", id.value()));
     }
     
+    #[test]
+    fn test_object_space_starts_at_one() {
+        let space = ObjectSpace::new();
+        
+        assert_eq!(space.new_id().value(), 1);
+        assert_eq!(space.new_id().value(), 2);
+        assert_eq!(space.new_id().value(), 3);
+    }
+    
+    #[test]
+    fn test_object_spaces_are_independent() {
+        let space1 = ObjectSpace::new();
+        let space2 = ObjectSpace::default();
+        
+        // Both spaces start from 1
+        assert_eq!(space1.new_id().value(), 1);
+        assert_eq!(space2.new_id().value(), 1);
+        
+        // Allocating in one space doesn't advance the other
+        space1.new_id();
+        space1.new_id();
+        assert_eq!(space2.new_id().value(), 2);
+        assert_eq!(space1.new_id().value(), 4);
+    }
+    
+    #[test]
+    fn test_object_space_reset_for_testing() {
+        let space = ObjectSpace::new();
+        space.new_id();
+        space.new_id();
+        
+        space.reset_for_testing();
+        
+        // Ids are reproducible after a reset
+        assert_eq!(space.new_id().value(), 1);
+        assert_eq!(space.new_id().value(), 2);
+    }
+    
+    #[test]
+    fn test_object_space_does_not_affect_global() {
+        let space = ObjectSpace::new();
+        let before = ObjectId::new();
+        
+        space.new_id();
+        let after = ObjectId::new();
+        
+        // Global ids remain unique and increasing regardless of other spaces
+        assert!(after.value() > before.value());
+    }
+    
     #[test]
     fn test_object_identity() {
         let obj1 = TestObject::new(42);
@@ -238,6 +789,26 @@ mod tests {
         assert!(string_repr.contains(&format!("{}", obj.object_id())));
     }
     
+    #[test]
+    fn test_as_i64() {
+        let num: Box<dyn SmalltalkObject> = Box::new(SmallInteger::new(-7));
+        let not_num: Box<dyn SmalltalkObject> = Box::new(True::new());
+        
+        assert_eq!(num.as_i64(), Some(-7));
+        assert_eq!(not_num.as_i64(), None);
+    }
+    
+    #[test]
+    fn test_as_bool() {
+        let true_obj: Box<dyn SmalltalkObject> = Box::new(True::new());
+        let false_obj: Box<dyn SmalltalkObject> = Box::new(False::new());
+        let not_bool: Box<dyn SmalltalkObject> = Box::new(SmallInteger::new(1));
+        
+        assert_eq!(true_obj.as_bool(), Some(true));
+        assert_eq!(false_obj.as_bool(), Some(false));
+        assert_eq!(not_bool.as_bool(), None);
+    }
+    
     #[test]
     fn test_object_id_value() {
         let id = ObjectId::new();
@@ -309,4 +880,108 @@ mod tests {
         assert!(string_repr.contains("DefaultStringObject"));
         assert!(string_repr.contains(&format!("{}", obj.object_id())));
     }
+    
+    #[test]
+    fn test_default_display_string() {
+        let obj = TestObject::new(42);
+        assert_eq!(obj.display_string(), obj.to_smalltalk_string());
+    }
+    
+    #[test]
+    fn test_print_nl_and_display_nl() {
+        use super::super::ring_buffer::RingBuffer;
+        use super::super::transcript::tests::SharedBuffer;
+        
+        // All checks share one test since they redirect the global Transcript
+        let buffer = SharedBuffer::default();
+        let previous = Transcript::current().set_sink(Box::new(buffer.clone()));
+        
+        let obj = SmallInteger::new(5);
+        let printed = obj.print_nl();
+        let displayed = obj.display_nl();
+        
+        // Trait objects, such as collection elements, use the dyn versions
+        let mut buffer_of_elements = RingBuffer::new(2);
+        buffer_of_elements.add(Box::new(SmallInteger::new(6)));
+        buffer_of_elements.add(Box::new(True::new()));
+        buffer_of_elements.do_each(|element| {
+            assert!(element.print_nl_dyn().display_nl_dyn().is_identical(element));
+        });
+        
+        Transcript::current().set_sink(previous);
+        
+        // Receiver is returned so messages can be chained
+        assert!(printed.is_identical(&obj));
+        assert!(displayed.is_identical(&obj));
+        
+        assert_eq!(buffer.contents(), "5\n5\n6\n6\ntrue\ntrue\n");
+    }
+    
+    #[test]
+    fn test_class_name() {
+        assert_eq!(TestObject::new(1).class_name(), "TestObject");
+        assert_eq!(SmallInteger::new(1).class_name(), "SmallInteger");
+        
+        // Dispatches to the concrete class through trait objects
+        let obj: Box<dyn SmalltalkObject> = Box::new(True::new());
+        assert_eq!(obj.class_name(), "True");
+        
+        // Inspector and debug tree report the same class name
+        let obj = TestObject::new(2);
+        assert_eq!(obj.debug_tree().class_name, "TestObject");
+        assert_eq!(obj.inspect()[0], ("class".to_string(), "TestObject".to_string()));
+    }
+    
+    #[test]
+    fn test_default_inspect() {
+        #[derive(Debug)]
+        struct DefaultInspectObject {
+            id: ObjectId,
+        }
+        
+        impl SmalltalkObject for DefaultInspectObject {
+            fn object_id(&self) -> ObjectId {
+                self.id
+            }
+            // Uses default inspect implementation
+        }
+        
+        let obj = DefaultInspectObject { id: ObjectId::new() };
+        let fields = obj.inspect();
+        
+        // Should list the class name and object ID
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0], ("class".to_string(), "DefaultInspectObject".to_string()));
+        assert_eq!(fields[1], ("id".to_string(), format!("{}", obj.object_id())));
+    }
+    
+    #[test]
+    fn test_default_references_empty() {
+        assert!(TestObject::new(1).references().is_empty());
+        assert!(SmallInteger::new(1).references().is_empty());
+    }
+    
+    #[test]
+    fn test_deep_size_of_leaf_is_shallow_size() {
+        let obj = TestObject::new(1);
+        
+        assert_eq!(obj.shallow_size(), std::mem::size_of::<TestObject>());
+        assert_eq!(obj.deep_size(), obj.shallow_size());
+    }
+    
+    #[test]
+    fn test_deep_size_terminates_on_cycle() {
+        let obj = SelfReferencing::new();
+        assert_eq!(obj.deep_size(), obj.shallow_size());
+    }
+    
+    #[test]
+    fn test_deep_size_sums_children() {
+        let key = SmallInteger::new(1);
+        let value = SmallInteger::new(2);
+        let expected_children = key.shallow_size() + value.shallow_size();
+        let assoc = key.associate(Box::new(value));
+        
+        assert_eq!(assoc.deep_size(), assoc.shallow_size() + expected_children);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,196 @@
+//! Bounded printing options for Smalltalk objects
+//! 
+//! Inspectors and debuggers print arbitrary object graphs, which may be huge
+//! or deeply nested. PrintOptions bounds the nesting depth, the number of
+//! elements shown per collection, and the total length of the output.
+//! Anything left out is replaced by an ellipsis.
+
+use super::object::SmalltalkObject;
+
+/// Marker printed in place of omitted elements, levels, or text
+pub const ELLIPSIS: &str = "...";
+
+/// Limits applied by `SmalltalkObject::print_string_with`
+/// 
+/// Every limit is optional; the default options are unbounded and print
+/// exactly like `to_smalltalk_string`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrintOptions {
+    /// Deepest nesting level printed; the receiver is at depth 0
+    pub max_depth: Option<usize>,
+    /// Maximum number of elements printed per collection
+    pub max_elements: Option<usize>,
+    /// Maximum number of characters in the whole output, ellipsis included
+    pub max_length: Option<usize>,
+}
+
+impl PrintOptions {
+    /// Prints an element nested inside a container
+    /// 
+    /// Containers call this for each element they print, passing the
+    /// element's own depth. Elements deeper than `max_depth` print as
+    /// an ellipsis.
+    /// 
+    /// # Arguments
+    /// * `element` - The nested object to print
+    /// * `depth` - Nesting depth of the element
+    /// 
+    /// # Returns
+    /// The element's bounded print string
+    pub fn print_element(&self, element: &dyn SmalltalkObject, depth: usize) -> String {
+        match self.max_depth {
+            Some(max_depth) if depth > max_depth => ELLIPSIS.to_string(),
+            _ => element.print_string_nested(self, depth),
+        }
+    }
+    
+    /// Prints a sequence of elements nested inside a container
+    /// 
+    /// At most `max_elements` elements are printed, followed by an
+    /// ellipsis if any were left out.
+    /// 
+    /// # Arguments
+    /// * `elements` - The nested objects, in print order
+    /// * `depth` - Nesting depth of the elements
+    /// 
+    /// # Returns
+    /// The printed elements, separated by spaces
+    pub fn print_elements<'a>(
+        &self,
+        elements: impl ExactSizeIterator<Item = &'a dyn SmalltalkObject>,
+        depth: usize,
+    ) -> String {
+        let total = elements.len();
+        let limit = self.max_elements.unwrap_or(total);
+        let mut printed: Vec<String> = elements
+            .take(limit)
+            .map(|element| self.print_element(element, depth))
+            .collect();
+        if total > limit {
+            printed.push(ELLIPSIS.to_string());
+        }
+        printed.join(" ")
+    }
+    
+    /// Truncates a complete print string to at most `max_length` characters
+    /// 
+    /// # Arguments
+    /// * `text` - The print string to bound
+    /// 
+    /// # Returns
+    /// The text, shortened so that it ends with an ellipsis and still fits
+    /// in `max_length` if it was too long
+    pub fn limit_length(&self, text: String) -> String {
+        match self.max_length {
+            Some(max_length) if text.chars().count() > max_length => {
+                let kept = max_length.saturating_sub(ELLIPSIS.len());
+                let mut truncated: String = text.chars().take(kept).collect();
+                truncated.extend(ELLIPSIS.chars().take(max_length - kept));
+                truncated
+            }
+            _ => text,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::association::Association;
+    use super::super::ring_buffer::tests::{filled, nested_buffers};
+    use super::super::small_integer::SmallInteger;
+    
+    #[test]
+    fn test_default_options_are_unbounded() {
+        let nested = nested_buffers(4);
+        let options = PrintOptions::default();
+        
+        assert_eq!(options.max_depth, None);
+        assert_eq!(nested.print_string_with(&options), nested.to_smalltalk_string());
+        assert_eq!(
+            nested.to_smalltalk_string(),
+            "a RingBuffer(a RingBuffer(a RingBuffer(a RingBuffer(1))))"
+        );
+    }
+    
+    #[test]
+    fn test_max_depth_truncates_nesting() {
+        let nested = nested_buffers(4);
+        let options = PrintOptions {
+            max_depth: Some(2),
+            ..PrintOptions::default()
+        };
+        
+        // Receiver at depth 0, its elements at 1 and 2, deeper levels elided
+        assert_eq!(
+            nested.print_string_with(&options),
+            "a RingBuffer(a RingBuffer(a RingBuffer(...)))"
+        );
+    }
+    
+    #[test]
+    fn test_max_depth_zero() {
+        let options = PrintOptions {
+            max_depth: Some(0),
+            ..PrintOptions::default()
+        };
+        
+        assert_eq!(nested_buffers(2).print_string_with(&options), "a RingBuffer(...)");
+        assert_eq!(SmallInteger::new(5).print_string_with(&options), "5");
+    }
+    
+    #[test]
+    fn test_max_depth_applies_to_associations() {
+        // 1 -> (2 -> 3)
+        let inner = SmallInteger::new(2).associate(Box::new(SmallInteger::new(3)));
+        let outer: Association = SmallInteger::new(1).associate(Box::new(inner));
+        let options = PrintOptions {
+            max_depth: Some(1),
+            ..PrintOptions::default()
+        };
+        
+        assert_eq!(outer.print_string_with(&options), "1->...->...");
+    }
+    
+    #[test]
+    fn test_max_elements_inserts_ellipsis() {
+        let buffer = filled(5, 1..=5);
+        let options = PrintOptions {
+            max_elements: Some(3),
+            ..PrintOptions::default()
+        };
+        
+        assert_eq!(buffer.print_string_with(&options), "a RingBuffer(1 2 3 ...)");
+        
+        // No ellipsis when everything fits
+        assert_eq!(filled(3, 1..=3).print_string_with(&options), "a RingBuffer(1 2 3)");
+    }
+    
+    #[test]
+    fn test_max_length_truncates_output() {
+        let buffer = filled(5, 1..=5);
+        let options = PrintOptions {
+            max_length: Some(15),
+            ..PrintOptions::default()
+        };
+        
+        // The ellipsis counts toward the limit
+        let printed = buffer.print_string_with(&options);
+        assert_eq!(printed, "a RingBuffer...");
+        assert_eq!(printed.chars().count(), 15);
+        
+        // Output exactly at the limit is left alone
+        assert_eq!(filled(1, 1..=1).print_string_with(&options), "a RingBuffer(1)");
+        assert_eq!(SmallInteger::new(42).print_string_with(&options), "42");
+    }
+    
+    #[test]
+    fn test_max_length_shorter_than_ellipsis() {
+        let options = PrintOptions {
+            max_length: Some(2),
+            ..PrintOptions::default()
+        };
+        
+        assert_eq!(filled(5, 1..=5).print_string_with(&options), "..");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,818 @@
+//! Processes and process synchronization primitives for Smalltalk
+//! 
+//! Smalltalk runs concurrent activities as lightweight Processes scheduled
+//! cooperatively by the ProcessorScheduler, and coordinates them through
+//! Semaphores and SharedQueues. Since every SmalltalkObject is `Send + Sync`,
+//! the synchronization primitives also work across native threads, where
+//! `wait` and `next` block the calling thread until another thread signals.
+//! Processes run by a ProcessScheduler share one thread, so they must not
+//! block: they use `try_wait` / `try_next`, or suspend on a semaphore by
+//! returning `ProcessStep::WaitOn` and are resumed once it is signaled.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+
+use super::object::{ObjectId, SmalltalkObject};
+
+/// A counting semaphore for synchronizing processes
+/// 
+/// Equivalent to Smalltalk's `Semaphore`. Each `signal` either wakes one
+/// waiting process or is remembered as an excess signal that lets a later
+/// `wait` proceed immediately.
+#[derive(Debug)]
+pub struct Semaphore {
+    id: ObjectId,
+    excess_signals: Mutex<usize>,
+    available: Condvar,
+}
+
+impl Semaphore {
+    /// Creates a new Semaphore with no excess signals
+    /// 
+    /// # Returns
+    /// A new Semaphore on which `wait` blocks until it is signaled
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::Semaphore;
+    /// let semaphore = Semaphore::new();
+    /// semaphore.signal();
+    /// semaphore.wait(); // Returns immediately, consuming the signal
+    /// ```
+    pub fn new() -> Self {
+        Self::with_signals(0)
+    }
+    
+    /// Creates a new Semaphore for mutual exclusion
+    /// 
+    /// Equivalent to Smalltalk's `Semaphore forMutualExclusion`: it starts
+    /// with one excess signal, so the first `wait` proceeds immediately.
+    /// 
+    /// # Returns
+    /// A new Semaphore with a single excess signal
+    pub fn for_mutual_exclusion() -> Self {
+        Self::with_signals(1)
+    }
+    
+    fn with_signals(signals: usize) -> Self {
+        Self {
+            id: ObjectId::new(),
+            excess_signals: Mutex::new(signals),
+            available: Condvar::new(),
+        }
+    }
+    
+    /// Signals the semaphore (equivalent to Smalltalk's `signal`)
+    /// 
+    /// Wakes one waiting process, or records an excess signal if no
+    /// process is waiting.
+    pub fn signal(&self) {
+        let mut signals = self.excess_signals.lock().unwrap();
+        *signals += 1;
+        self.available.notify_one();
+    }
+    
+    /// Waits for the semaphore to be signaled (equivalent to Smalltalk's `wait`)
+    /// 
+    /// Consumes an excess signal if one is available, otherwise blocks the
+    /// calling thread until another thread calls `signal`. Processes run by
+    /// a ProcessScheduler should return `ProcessStep::WaitOn` instead, since
+    /// blocking would stall every process on the scheduler's thread.
+    pub fn wait(&self) {
+        let mut signals = self.excess_signals.lock().unwrap();
+        while *signals == 0 {
+            signals = self.available.wait(signals).unwrap();
+        }
+        *signals -= 1;
+    }
+    
+    /// Consumes an excess signal if one is available, without blocking
+    /// 
+    /// # Returns
+    /// True if a signal was consumed, false if the semaphore has none
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::Semaphore;
+    /// let semaphore = Semaphore::new();
+    /// assert!(!semaphore.try_wait());
+    /// semaphore.signal();
+    /// assert!(semaphore.try_wait());
+    /// ```
+    pub fn try_wait(&self) -> bool {
+        let mut signals = self.excess_signals.lock().unwrap();
+        if *signals == 0 {
+            return false;
+        }
+        *signals -= 1;
+        true
+    }
+    
+    /// Returns the number of signals not yet consumed by a `wait`
+    /// 
+    /// Equivalent to Smalltalk's `excessSignals`.
+    /// 
+    /// # Returns
+    /// The count of pending signals
+    pub fn excess_signals(&self) -> usize {
+        *self.excess_signals.lock().unwrap()
+    }
+}
+
+impl SmalltalkObject for Semaphore {
+    fn object_id(&self) -> ObjectId {
+        self.id
+    }
+    
+    fn to_smalltalk_string(&self) -> String {
+        "a Semaphore".to_string()
+    }
+    
+    fn inspect(&self) -> Vec<(String, String)> {
+        vec![("excessSignals".to_string(), self.excess_signals().to_string())]
+    }
+}
+
+/// A thread-safe FIFO queue for passing objects between processes
+/// 
+/// Equivalent to Smalltalk's `SharedQueue`. Producers add objects with
+/// `next_put` and consumers take them with `next`, which blocks while the
+/// queue is empty.
+#[derive(Debug)]
+pub struct SharedQueue {
+    id: ObjectId,
+    items: Mutex<VecDeque<Box<dyn SmalltalkObject>>>,
+    available: Condvar,
+}
+
+impl SharedQueue {
+    /// Creates a new empty SharedQueue
+    /// 
+    /// # Returns
+    /// A new SharedQueue with no items
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::{SharedQueue, SmallInteger};
+    /// let queue = SharedQueue::new();
+    /// queue.next_put(Box::new(SmallInteger::new(42)));
+    /// assert_eq!(queue.size(), 1);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            id: ObjectId::new(),
+            items: Mutex::new(VecDeque::new()),
+            available: Condvar::new(),
+        }
+    }
+    
+    /// Adds an object to the end of the queue (equivalent to Smalltalk's `nextPut:`)
+    /// 
+    /// Wakes one consumer blocked in `next`, if any.
+    /// 
+    /// # Arguments
+    /// * `item` - The object to enqueue
+    pub fn next_put(&self, item: Box<dyn SmalltalkObject>) {
+        self.items.lock().unwrap().push_back(item);
+        self.available.notify_one();
+    }
+    
+    /// Removes and returns the first object (equivalent to Smalltalk's `next`)
+    /// 
+    /// Blocks the calling thread until an object is available.
+    /// 
+    /// # Returns
+    /// The oldest object in the queue
+    pub fn next(&self) -> Box<dyn SmalltalkObject> {
+        let mut items = self.items.lock().unwrap();
+        loop {
+            if let Some(item) = items.pop_front() {
+                return item;
+            }
+            items = self.available.wait(items).unwrap();
+        }
+    }
+    
+    /// Removes and returns the first object if there is one, without blocking
+    /// 
+    /// Scheduled processes poll with this and yield while the queue is empty.
+    /// 
+    /// # Returns
+    /// The oldest object in the queue, or None if the queue is empty
+    pub fn try_next(&self) -> Option<Box<dyn SmalltalkObject>> {
+        self.items.lock().unwrap().pop_front()
+    }
+    
+    /// Returns the number of objects in the queue
+    /// 
+    /// # Returns
+    /// The current queue size
+    pub fn size(&self) -> usize {
+        self.items.lock().unwrap().len()
+    }
+    
+    /// Tests whether the queue has no objects (equivalent to Smalltalk's `isEmpty`)
+    /// 
+    /// # Returns
+    /// True if the queue is empty
+    pub fn is_empty(&self) -> bool {
+        self.items.lock().unwrap().is_empty()
+    }
+}
+
+impl SmalltalkObject for SharedQueue {
+    fn object_id(&self) -> ObjectId {
+        self.id
+    }
+    
+    fn to_smalltalk_string(&self) -> String {
+        "a SharedQueue".to_string()
+    }
+    
+    fn inspect(&self) -> Vec<(String, String)> {
+        vec![("size".to_string(), self.size().to_string())]
+    }
+}
+
+/// Default priority for processes forked without an explicit priority
+/// 
+/// Matches Smalltalk's `Processor userSchedulingPriority`.
+pub const USER_SCHEDULING_PRIORITY: usize = 40;
+
+/// Outcome of running one step of a process body
+/// 
+/// A process body runs until its next yield point and reports whether it
+/// wants to be rescheduled, is waiting on a semaphore, or has finished.
+#[derive(Debug, Clone)]
+pub enum ProcessStep {
+    /// The process reached a yield point and should run again later
+    Yield,
+    /// The process is suspended until the semaphore is signaled
+    /// 
+    /// The scheduler consumes the signal on the process's behalf before
+    /// running it again, like Smalltalk's `Semaphore>>wait`.
+    WaitOn(Arc<Semaphore>),
+    /// The process has run to completion
+    Done,
+}
+
+/// A lightweight green-thread process
+/// 
+/// Equivalent to Smalltalk's `Process`. The body is called once per
+/// scheduling step; each call runs up to the next yield point and returns
+/// a `ProcessStep`, so processes multitask cooperatively on a single thread.
+pub struct Process {
+    id: ObjectId,
+    priority: usize,
+    body: Mutex<Box<dyn FnMut() -> ProcessStep + Send>>,
+    terminated: AtomicBool,
+}
+
+impl Process {
+    /// Creates a new process that has not yet been scheduled
+    /// 
+    /// # Arguments
+    /// * `priority` - Scheduling priority; higher priorities run first
+    /// * `body` - Closure run once per step until it returns `ProcessStep::Done`
+    /// 
+    /// # Returns
+    /// A new Process
+    pub fn new(priority: usize, body: impl FnMut() -> ProcessStep + Send + 'static) -> Self {
+        Self {
+            id: ObjectId::new(),
+            priority,
+            body: Mutex::new(Box::new(body)),
+            terminated: AtomicBool::new(false),
+        }
+    }
+    
+    /// Returns the scheduling priority of this process
+    /// 
+    /// # Returns
+    /// The priority given when the process was created
+    pub fn priority(&self) -> usize {
+        self.priority
+    }
+    
+    /// Tests whether this process has run to completion (equivalent to Smalltalk's `isTerminated`)
+    /// 
+    /// # Returns
+    /// True once the body has returned `ProcessStep::Done`
+    pub fn is_terminated(&self) -> bool {
+        self.terminated.load(Ordering::Acquire)
+    }
+    
+    /// Runs the body up to its next yield point
+    fn step(&self) -> ProcessStep {
+        let result = (self.body.lock().unwrap())();
+        if matches!(result, ProcessStep::Done) {
+            self.terminated.store(true, Ordering::Release);
+        }
+        result
+    }
+}
+
+impl std::fmt::Debug for Process {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Process")
+            .field("id", &self.id)
+            .field("priority", &self.priority)
+            .field("terminated", &self.is_terminated())
+            .finish()
+    }
+}
+
+impl SmalltalkObject for Process {
+    fn object_id(&self) -> ObjectId {
+        self.id
+    }
+    
+    fn to_smalltalk_string(&self) -> String {
+        format!("a Process in priority {}", self.priority)
+    }
+    
+    fn inspect(&self) -> Vec<(String, String)> {
+        vec![
+            ("priority".to_string(), self.priority.to_string()),
+            ("isTerminated".to_string(), self.is_terminated().to_string()),
+        ]
+    }
+}
+
+/// Cooperative scheduler for lightweight processes
+/// 
+/// Equivalent to Smalltalk's `ProcessorScheduler`. The highest priority ready
+/// process always runs next; processes of equal priority take turns in
+/// round-robin order each time one of them yields. Processes waiting on a
+/// semaphore are held aside, in the order they started waiting, until it
+/// is signaled.
+#[derive(Debug)]
+pub struct ProcessScheduler {
+    id: ObjectId,
+    ready: Mutex<BTreeMap<usize, VecDeque<Arc<Process>>>>,
+    waiting: Mutex<VecDeque<(Arc<Semaphore>, Arc<Process>)>>,
+}
+
+impl ProcessScheduler {
+    /// Creates a new scheduler with no ready processes
+    /// 
+    /// # Returns
+    /// A new ProcessScheduler
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::{ProcessScheduler, ProcessStep};
+    /// let scheduler = ProcessScheduler::new();
+    /// let process = scheduler.fork(|| ProcessStep::Done);
+    /// scheduler.run();
+    /// assert!(process.is_terminated());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            id: ObjectId::new(),
+            ready: Mutex::new(BTreeMap::new()),
+            waiting: Mutex::new(VecDeque::new()),
+        }
+    }
+    
+    /// Forks a new process at the user scheduling priority (equivalent to Smalltalk's `fork`)
+    /// 
+    /// # Arguments
+    /// * `body` - Closure run once per step until it returns `ProcessStep::Done`
+    /// 
+    /// # Returns
+    /// The newly scheduled process
+    pub fn fork(&self, body: impl FnMut() -> ProcessStep + Send + 'static) -> Arc<Process> {
+        self.fork_at(USER_SCHEDULING_PRIORITY, body)
+    }
+    
+    /// Forks a new process at the given priority (equivalent to Smalltalk's `forkAt:`)
+    /// 
+    /// # Arguments
+    /// * `priority` - Scheduling priority; higher priorities run first
+    /// * `body` - Closure run once per step until it returns `ProcessStep::Done`
+    /// 
+    /// # Returns
+    /// The newly scheduled process
+    pub fn fork_at(
+        &self,
+        priority: usize,
+        body: impl FnMut() -> ProcessStep + Send + 'static,
+    ) -> Arc<Process> {
+        let process = Arc::new(Process::new(priority, body));
+        self.schedule(Arc::clone(&process));
+        process
+    }
+    
+    /// Returns the number of processes waiting to run
+    /// 
+    /// # Returns
+    /// The count of ready processes across all priorities
+    pub fn ready_count(&self) -> usize {
+        self.ready.lock().unwrap().values().map(VecDeque::len).sum()
+    }
+    
+    /// Returns the number of processes suspended on a semaphore
+    /// 
+    /// # Returns
+    /// The count of processes waiting for a signal
+    pub fn waiting_count(&self) -> usize {
+        self.waiting.lock().unwrap().len()
+    }
+    
+    /// Runs processes until none is ready
+    /// 
+    /// Each iteration first resumes waiting processes whose semaphore has
+    /// been signaled, then steps the highest priority ready process. A
+    /// process that yields goes to the back of its priority's queue, and one
+    /// that returns `ProcessStep::WaitOn` waits for a signal. Processes may
+    /// fork new processes while running.
+    /// 
+    /// Returns once every process has terminated or is waiting on a
+    /// semaphore nobody has signaled; calling `run` again after a signal
+    /// resumes them.
+    pub fn run(&self) {
+        loop {
+            self.resume_signaled();
+            let Some(process) = self.next_ready() else {
+                return;
+            };
+            match process.step() {
+                ProcessStep::Yield => self.schedule(process),
+                ProcessStep::WaitOn(semaphore) => self.wait_on(semaphore, process),
+                ProcessStep::Done => {}
+            }
+        }
+    }
+    
+    fn wait_on(&self, semaphore: Arc<Semaphore>, process: Arc<Process>) {
+        if semaphore.try_wait() {
+            self.schedule(process);
+        } else {
+            self.waiting.lock().unwrap().push_back((semaphore, process));
+        }
+    }
+    
+    fn resume_signaled(&self) {
+        let mut waiting = self.waiting.lock().unwrap();
+        let mut still_waiting = VecDeque::with_capacity(waiting.len());
+        for (semaphore, process) in waiting.drain(..) {
+            if semaphore.try_wait() {
+                self.schedule(process);
+            } else {
+                still_waiting.push_back((semaphore, process));
+            }
+        }
+        *waiting = still_waiting;
+    }
+    
+    fn schedule(&self, process: Arc<Process>) {
+        self.ready
+            .lock()
+            .unwrap()
+            .entry(process.priority())
+            .or_default()
+            .push_back(process);
+    }
+    
+    fn next_ready(&self) -> Option<Arc<Process>> {
+        let mut ready = self.ready.lock().unwrap();
+        let (&priority, queue) = ready.iter_mut().next_back()?;
+        let process = queue.pop_front();
+        if queue.is_empty() {
+            ready.remove(&priority);
+        }
+        process
+    }
+}
+
+impl SmalltalkObject for ProcessScheduler {
+    fn object_id(&self) -> ObjectId {
+        self.id
+    }
+    
+    fn to_smalltalk_string(&self) -> String {
+        "a ProcessorScheduler".to_string()
+    }
+    
+    fn inspect(&self) -> Vec<(String, String)> {
+        vec![
+            ("readyProcesses".to_string(), self.ready_count().to_string()),
+            ("waitingProcesses".to_string(), self.waiting_count().to_string()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::small_integer::SmallInteger;
+    use std::thread;
+    use std::time::Duration;
+    
+    #[test]
+    fn test_semaphore_excess_signals() {
+        let semaphore = Semaphore::new();
+        assert_eq!(semaphore.excess_signals(), 0);
+        
+        semaphore.signal();
+        semaphore.signal();
+        assert_eq!(semaphore.excess_signals(), 2);
+        
+        // Each wait consumes one pending signal without blocking
+        semaphore.wait();
+        assert_eq!(semaphore.excess_signals(), 1);
+    }
+    
+    #[test]
+    fn test_semaphore_for_mutual_exclusion() {
+        let semaphore = Semaphore::for_mutual_exclusion();
+        assert_eq!(semaphore.excess_signals(), 1);
+        
+        // First wait proceeds immediately
+        semaphore.wait();
+        assert_eq!(semaphore.excess_signals(), 0);
+    }
+    
+    #[test]
+    fn test_semaphore_releases_waiting_consumer() {
+        let semaphore = Arc::new(Semaphore::new());
+        let released = Arc::new(Mutex::new(false));
+        
+        let consumer = {
+            let semaphore = Arc::clone(&semaphore);
+            let released = Arc::clone(&released);
+            thread::spawn(move || {
+                semaphore.wait();
+                *released.lock().unwrap() = true;
+            })
+        };
+        
+        // Consumer stays blocked until the producer signals
+        thread::sleep(Duration::from_millis(50));
+        assert!(!*released.lock().unwrap());
+        
+        semaphore.signal();
+        consumer.join().unwrap();
+        assert!(*released.lock().unwrap());
+        assert_eq!(semaphore.excess_signals(), 0);
+    }
+    
+    #[test]
+    fn test_shared_queue_fifo_order() {
+        let queue = SharedQueue::new();
+        assert!(queue.is_empty());
+        
+        queue.next_put(Box::new(SmallInteger::new(1)));
+        queue.next_put(Box::new(SmallInteger::new(2)));
+        assert_eq!(queue.size(), 2);
+        
+        assert_eq!(queue.next().to_smalltalk_string(), "1");
+        assert_eq!(queue.next().to_smalltalk_string(), "2");
+        assert!(queue.is_empty());
+    }
+    
+    #[test]
+    fn test_shared_queue_between_threads() {
+        let queue = Arc::new(SharedQueue::new());
+        
+        let consumer = {
+            let queue = Arc::clone(&queue);
+            thread::spawn(move || {
+                (0..5)
+                    .map(|_| queue.next().to_smalltalk_string())
+                    .collect::<Vec<_>>()
+            })
+        };
+        
+        let producer = {
+            let queue = Arc::clone(&queue);
+            thread::spawn(move || {
+                for i in 0..5 {
+                    queue.next_put(Box::new(SmallInteger::new(i)));
+                }
+            })
+        };
+        
+        producer.join().unwrap();
+        let received = consumer.join().unwrap();
+        
+        assert_eq!(received, vec!["0", "1", "2", "3", "4"]);
+        assert!(queue.is_empty());
+    }
+    
+    /// Creates a process body that logs `name-1`..`name-steps`, yielding after each entry
+    fn logging_body(
+        name: &'static str,
+        steps: usize,
+        log: &Arc<Mutex<Vec<String>>>,
+    ) -> impl FnMut() -> ProcessStep + Send + 'static {
+        let log = Arc::clone(log);
+        let mut step = 0;
+        move || {
+            step += 1;
+            log.lock().unwrap().push(format!("{}-{}", name, step));
+            if step == steps {
+                ProcessStep::Done
+            } else {
+                ProcessStep::Yield
+            }
+        }
+    }
+    
+    #[test]
+    fn test_forked_processes_run_to_completion() {
+        let scheduler = ProcessScheduler::new();
+        let log = Arc::new(Mutex::new(Vec::new()));
+        
+        let a = scheduler.fork(logging_body("a", 3, &log));
+        let b = scheduler.fork(logging_body("b", 2, &log));
+        assert_eq!(scheduler.ready_count(), 2);
+        assert!(!a.is_terminated());
+        
+        scheduler.run();
+        
+        assert!(a.is_terminated());
+        assert!(b.is_terminated());
+        assert_eq!(scheduler.ready_count(), 0);
+        assert_eq!(log.lock().unwrap().len(), 5);
+    }
+    
+    #[test]
+    fn test_equal_priority_processes_take_turns() {
+        let scheduler = ProcessScheduler::new();
+        let log = Arc::new(Mutex::new(Vec::new()));
+        
+        scheduler.fork(logging_body("a", 3, &log));
+        scheduler.fork(logging_body("b", 2, &log));
+        scheduler.run();
+        
+        // Yielding lets the other process at the same priority run
+        assert_eq!(*log.lock().unwrap(), vec!["a-1", "b-1", "a-2", "b-2", "a-3"]);
+    }
+    
+    #[test]
+    fn test_higher_priority_runs_first() {
+        let scheduler = ProcessScheduler::new();
+        let log = Arc::new(Mutex::new(Vec::new()));
+        
+        let low = scheduler.fork_at(10, logging_body("low", 2, &log));
+        let high = scheduler.fork_at(50, logging_body("high", 2, &log));
+        assert_eq!(low.priority(), 10);
+        assert_eq!(high.priority(), 50);
+        
+        scheduler.run();
+        
+        // Yielding doesn't give way to a lower priority process
+        assert_eq!(*log.lock().unwrap(), vec!["high-1", "high-2", "low-1", "low-2"]);
+    }
+    
+    #[test]
+    fn test_process_can_fork_while_running() {
+        let scheduler = Arc::new(ProcessScheduler::new());
+        let log = Arc::new(Mutex::new(Vec::new()));
+        
+        let parent = {
+            let scheduler_ref = Arc::clone(&scheduler);
+            let log = Arc::clone(&log);
+            move || {
+                scheduler_ref.fork_at(60, logging_body("child", 1, &log));
+                log.lock().unwrap().push("parent".to_string());
+                ProcessStep::Done
+            }
+        };
+        scheduler.fork(parent);
+        scheduler.run();
+        
+        assert_eq!(*log.lock().unwrap(), vec!["parent", "child-1"]);
+    }
+    
+    #[test]
+    fn test_try_wait_and_try_next_never_block() {
+        let semaphore = Semaphore::new();
+        assert!(!semaphore.try_wait());
+        semaphore.signal();
+        assert!(semaphore.try_wait());
+        assert_eq!(semaphore.excess_signals(), 0);
+        
+        let queue = SharedQueue::new();
+        assert!(queue.try_next().is_none());
+        queue.next_put(Box::new(SmallInteger::new(1)));
+        assert_eq!(queue.try_next().unwrap().to_smalltalk_string(), "1");
+        assert!(queue.is_empty());
+    }
+    
+    #[test]
+    fn test_processes_hand_off_through_semaphore() {
+        let scheduler = ProcessScheduler::new();
+        let semaphore = Arc::new(Semaphore::new());
+        let log = Arc::new(Mutex::new(Vec::new()));
+        
+        // Consumer waits first, then runs again once signaled
+        let consumer = {
+            let semaphore = Arc::clone(&semaphore);
+            let log = Arc::clone(&log);
+            let mut waited = false;
+            scheduler.fork(move || {
+                if !waited {
+                    waited = true;
+                    log.lock().unwrap().push("consumer waits");
+                    return ProcessStep::WaitOn(Arc::clone(&semaphore));
+                }
+                log.lock().unwrap().push("consumer resumes");
+                ProcessStep::Done
+            })
+        };
+        let producer = {
+            let semaphore = Arc::clone(&semaphore);
+            let log = Arc::clone(&log);
+            scheduler.fork(move || {
+                log.lock().unwrap().push("producer signals");
+                semaphore.signal();
+                ProcessStep::Done
+            })
+        };
+        
+        scheduler.run();
+        
+        assert!(consumer.is_terminated());
+        assert!(producer.is_terminated());
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["consumer waits", "producer signals", "consumer resumes"]
+        );
+        // The signal was consumed on the consumer's behalf
+        assert_eq!(semaphore.excess_signals(), 0);
+        assert_eq!(scheduler.waiting_count(), 0);
+    }
+    
+    #[test]
+    fn test_wait_on_signaled_semaphore_continues() {
+        let scheduler = ProcessScheduler::new();
+        let semaphore = Arc::new(Semaphore::for_mutual_exclusion());
+        
+        let mut steps = 0;
+        let waiter = Arc::clone(&semaphore);
+        let process = scheduler.fork(move || {
+            steps += 1;
+            if steps == 1 {
+                ProcessStep::WaitOn(Arc::clone(&waiter))
+            } else {
+                ProcessStep::Done
+            }
+        });
+        scheduler.run();
+        
+        // An excess signal lets the process continue without being held aside
+        assert!(process.is_terminated());
+        assert_eq!(semaphore.excess_signals(), 0);
+    }
+    
+    #[test]
+    fn test_run_returns_while_processes_wait() {
+        let scheduler = ProcessScheduler::new();
+        let semaphore = Arc::new(Semaphore::new());
+        
+        let mut waited = false;
+        let waiter = Arc::clone(&semaphore);
+        let process = scheduler.fork(move || {
+            if waited {
+                return ProcessStep::Done;
+            }
+            waited = true;
+            ProcessStep::WaitOn(Arc::clone(&waiter))
+        });
+        
+        // Nothing signals, so run returns instead of hanging
+        scheduler.run();
+        assert!(!process.is_terminated());
+        assert_eq!(scheduler.waiting_count(), 1);
+        assert_eq!(scheduler.ready_count(), 0);
+        
+        // A signal from outside the scheduler resumes it on the next run
+        semaphore.signal();
+        scheduler.run();
+        assert!(process.is_terminated());
+        assert_eq!(scheduler.waiting_count(), 0);
+    }
+    
+    #[test]
+    fn test_smalltalk_object_implementation() {
+        let semaphore = Semaphore::new();
+        let queue = SharedQueue::new();
+        
+        assert_eq!(semaphore.to_smalltalk_string(), "a Semaphore");
+        assert_eq!(queue.to_smalltalk_string(), "a SharedQueue");
+        assert_ne!(semaphore.object_id(), queue.object_id());
+        
+        // Synchronization objects use identity equality
+        assert!(semaphore.equals(&semaphore));
+        assert!(!semaphore.equals(&Semaphore::new()));
+        
+        let scheduler = ProcessScheduler::new();
+        let process = scheduler.fork_at(30, || ProcessStep::Done);
+        assert_eq!(process.to_smalltalk_string(), "a Process in priority 30");
+        assert_eq!(scheduler.to_smalltalk_string(), "a ProcessorScheduler");
+    }
+}
\ No newline at end of file
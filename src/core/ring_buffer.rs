@@ -0,0 +1,367 @@
+//! RingBuffer implementation for Smalltalk
+//! 
+//! A RingBuffer is a bounded collection that keeps only the most recently
+//! added elements. Once full, each new element overwrites the oldest one,
+//! which makes it suitable for logs and sliding-window computations.
+
+use std::collections::VecDeque;
+
+use super::object::{ObjectId, SmalltalkObject};
+use super::printing::PrintOptions;
+
+/// Fixed-capacity collection that overwrites its oldest element when full
+/// 
+/// Elements are kept in insertion order, from oldest to newest.
+#[derive(Debug)]
+pub struct RingBuffer {
+    id: ObjectId,
+    capacity: usize,
+    elements: VecDeque<Box<dyn SmalltalkObject>>,
+}
+
+impl RingBuffer {
+    /// Creates a new empty RingBuffer
+    /// 
+    /// # Arguments
+    /// * `capacity` - Maximum number of elements kept
+    /// 
+    /// # Returns
+    /// A new RingBuffer with the given capacity. A zero-capacity buffer
+    /// stays empty and hands every added element straight back.
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::{RingBuffer, SmallInteger};
+    /// let mut buffer = RingBuffer::new(2);
+    /// buffer.add(Box::new(SmallInteger::new(1)));
+    /// assert_eq!(buffer.size(), 1);
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            id: ObjectId::new(),
+            capacity,
+            elements: VecDeque::with_capacity(capacity),
+        }
+    }
+    
+    /// Returns the maximum number of elements kept
+    /// 
+    /// # Returns
+    /// The capacity given at creation
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+    
+    /// Returns the number of elements currently held (equivalent to Smalltalk's `size`)
+    /// 
+    /// # Returns
+    /// The element count, never more than the capacity
+    pub fn size(&self) -> usize {
+        self.elements.len()
+    }
+    
+    /// Tests whether the buffer holds no elements (equivalent to Smalltalk's `isEmpty`)
+    /// 
+    /// # Returns
+    /// True if the buffer is empty
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+    
+    /// Tests whether the next `add` will overwrite the oldest element
+    /// 
+    /// # Returns
+    /// True if the buffer holds `capacity` elements
+    pub fn is_full(&self) -> bool {
+        self.elements.len() == self.capacity
+    }
+    
+    /// Adds an element as the newest (equivalent to Smalltalk's `add:`)
+    /// 
+    /// If the buffer is full, the oldest element is removed to make room.
+    /// 
+    /// # Arguments
+    /// * `element` - The object to add
+    /// 
+    /// # Returns
+    /// The overwritten oldest element, if the buffer was full, or `element`
+    /// itself if the capacity is zero
+    pub fn add(&mut self, element: Box<dyn SmalltalkObject>) -> Option<Box<dyn SmalltalkObject>> {
+        if self.capacity == 0 {
+            return Some(element);
+        }
+        let evicted = if self.is_full() {
+            self.elements.pop_front()
+        } else {
+            None
+        };
+        self.elements.push_back(element);
+        evicted
+    }
+    
+    /// Evaluates a closure for each element from oldest to newest
+    /// 
+    /// Equivalent to Smalltalk's `do:`.
+    /// 
+    /// # Arguments
+    /// * `block` - Closure called with each element in order
+    pub fn do_each(&self, mut block: impl FnMut(&dyn SmalltalkObject)) {
+        for element in &self.elements {
+            block(element.as_ref());
+        }
+    }
+}
+
+impl SmalltalkObject for RingBuffer {
+    fn object_id(&self) -> ObjectId {
+        self.id
+    }
+    
+    fn equals(&self, other: &dyn SmalltalkObject) -> bool {
+        // The same buffer is equal to itself without walking the elements;
+        // addresses, unlike ids, can't collide across ObjectSpaces
+        if std::ptr::addr_eq(self, other) {
+            return true;
+        }
+        if let Some(other_buffer) = other.as_any().downcast_ref::<RingBuffer>() {
+            self.capacity == other_buffer.capacity
+                && self.elements.len() == other_buffer.elements.len()
+                && self
+                    .elements
+                    .iter()
+                    .zip(&other_buffer.elements)
+                    .all(|(a, b)| a.equals(b.as_ref()))
+        } else {
+            false
+        }
+    }
+    
+    fn to_smalltalk_string(&self) -> String {
+        self.print_string_with(&PrintOptions::default())
+    }
+    
+    fn print_string_nested(&self, options: &PrintOptions, depth: usize) -> String {
+        let elements = self.elements.iter().map(|e| e.as_ref());
+        format!("a RingBuffer({})", options.print_elements(elements, depth + 1))
+    }
+    
+    fn inspect(&self) -> Vec<(String, String)> {
+        self.elements
+            .iter()
+            .enumerate()
+            .map(|(i, e)| ((i + 1).to_string(), e.to_smalltalk_string()))
+            .collect()
+    }
+    
+    fn shallow_size(&self) -> usize {
+        std::mem::size_of_val(self)
+            + self.elements.capacity() * std::mem::size_of::<Box<dyn SmalltalkObject>>()
+    }
+    
+    fn child_objects(&self) -> Vec<(String, &dyn SmalltalkObject)> {
+        self.elements
+            .iter()
+            .enumerate()
+            .map(|(i, e)| ((i + 1).to_string(), e.as_ref()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use super::super::object::tests::WithId;
+    use super::super::small_integer::SmallInteger;
+    
+    /// Builds a RingBuffer of the given capacity after adding each value in order
+    pub(crate) fn filled(capacity: usize, values: impl IntoIterator<Item = i64>) -> RingBuffer {
+        let mut buffer = RingBuffer::new(capacity);
+        for value in values {
+            buffer.add(Box::new(SmallInteger::new(value)));
+        }
+        buffer
+    }
+    
+    /// Builds `levels` RingBuffers nested inside each other around the integer 1
+    pub(crate) fn nested_buffers(levels: usize) -> Box<dyn SmalltalkObject> {
+        let mut object: Box<dyn SmalltalkObject> = Box::new(SmallInteger::new(1));
+        for _ in 0..levels {
+            let mut buffer = RingBuffer::new(1);
+            buffer.add(object);
+            object = Box::new(buffer);
+        }
+        object
+    }
+    
+    fn contents(buffer: &RingBuffer) -> Vec<String> {
+        let mut result = Vec::new();
+        buffer.do_each(|e| result.push(e.to_smalltalk_string()));
+        result
+    }
+    
+    #[test]
+    fn test_ring_buffer_creation() {
+        let buffer = RingBuffer::new(3);
+        
+        assert_eq!(buffer.capacity(), 3);
+        assert_eq!(buffer.size(), 0);
+        assert!(buffer.is_empty());
+        assert!(!buffer.is_full());
+    }
+    
+    #[test]
+    fn test_ring_buffer_zero_capacity() {
+        let mut buffer = RingBuffer::new(0);
+        
+        assert!(buffer.is_empty());
+        assert!(buffer.is_full());
+        let returned = buffer.add(Box::new(SmallInteger::new(7)));
+        assert_eq!(returned.unwrap().as_i64(), Some(7));
+        assert_eq!(buffer.size(), 0);
+    }
+    
+    #[test]
+    fn test_add_within_capacity() {
+        let buffer = filled(3, [1, 2]);
+        
+        assert_eq!(buffer.size(), 2);
+        assert_eq!(contents(&buffer), vec!["1", "2"]);
+    }
+    
+    #[test]
+    fn test_add_past_capacity_keeps_most_recent() {
+        // capacity + 2 elements
+        let buffer = filled(3, [1, 2, 3, 4, 5]);
+        
+        assert!(buffer.is_full());
+        assert_eq!(buffer.size(), 3);
+        assert_eq!(contents(&buffer), vec!["3", "4", "5"]);
+    }
+    
+    #[test]
+    fn test_add_returns_overwritten_element() {
+        let mut buffer = filled(2, [1, 2]);
+        
+        let evicted = buffer.add(Box::new(SmallInteger::new(3)));
+        assert_eq!(evicted.unwrap().to_smalltalk_string(), "1");
+        
+        let mut not_full = RingBuffer::new(2);
+        assert!(not_full.add(Box::new(SmallInteger::new(1))).is_none());
+    }
+    
+    #[test]
+    fn test_ring_buffer_equality() {
+        let a = filled(3, [1, 2, 3, 4]);
+        let b = filled(3, [2, 3, 4]);
+        let different_order = filled(3, [4, 3, 2]);
+        let different_capacity = filled(4, [2, 3, 4]);
+        
+        assert!(a.equals(&b));
+        assert!(!a.equals(&different_order));
+        assert!(!a.equals(&different_capacity));
+        assert!(!a.equals(&SmallInteger::new(2)));
+    }
+    
+    #[test]
+    fn test_equals_self_skips_elements() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        
+        /// Element that counts how often it is compared
+        #[derive(Debug)]
+        struct CountingElement {
+            id: ObjectId,
+            comparisons: Arc<AtomicUsize>,
+        }
+        
+        impl SmalltalkObject for CountingElement {
+            fn object_id(&self) -> ObjectId {
+                self.id
+            }
+            
+            fn equals(&self, other: &dyn SmalltalkObject) -> bool {
+                self.comparisons.fetch_add(1, Ordering::SeqCst);
+                other.as_any().is::<CountingElement>()
+            }
+        }
+        
+        let comparisons = Arc::new(AtomicUsize::new(0));
+        let counting_buffer = || {
+            let mut buffer = RingBuffer::new(1000);
+            for _ in 0..1000 {
+                buffer.add(Box::new(CountingElement {
+                    id: ObjectId::new(),
+                    comparisons: Arc::clone(&comparisons),
+                }));
+            }
+            buffer
+        };
+        let a = counting_buffer();
+        let b = counting_buffer();
+        
+        // Comparing to itself never touches the elements
+        assert!(a.equals(&a));
+        assert_eq!(comparisons.load(Ordering::SeqCst), 0);
+        
+        // An equal but distinct buffer is still compared element by element
+        assert!(a.equals(&b));
+        assert_eq!(comparisons.load(Ordering::SeqCst), 1000);
+    }
+    
+    #[test]
+    fn test_ring_buffer_references() {
+        let mut buffer = RingBuffer::new(2);
+        let mut ids = Vec::new();
+        for value in [1, 2, 3] {
+            let element = SmallInteger::new(value);
+            ids.push(element.object_id());
+            buffer.add(Box::new(element));
+        }
+        
+        // Only the elements still held, oldest first
+        assert_eq!(buffer.references(), ids[1..]);
+        assert!(RingBuffer::new(2).references().is_empty());
+    }
+    
+    #[test]
+    fn test_deep_size_counts_shared_element_once() {
+        // Clones of a cached SmallInteger share its object id
+        let shared = SmallInteger::cached(5);
+        let mut buffer = RingBuffer::new(3);
+        buffer.add(Box::new(shared.clone().into_owned()));
+        buffer.add(Box::new(shared.clone().into_owned()));
+        
+        assert_eq!(buffer.deep_size(), buffer.shallow_size() + shared.shallow_size());
+        
+        // Distinct but equal elements are each counted
+        let distinct = filled(3, [5, 5]);
+        let element_size = SmallInteger::new(5).shallow_size();
+        assert_eq!(distinct.deep_size(), distinct.shallow_size() + 2 * element_size);
+    }
+    
+    #[test]
+    fn test_equals_ignores_foreign_object_with_same_id() {
+        let buffer = filled(2, [1]);
+        let foreign = WithId::new(buffer.object_id());
+        
+        assert!(!buffer.equals(&foreign));
+    }
+    
+    #[test]
+    fn test_ring_buffer_to_string() {
+        assert_eq!(filled(2, [1, 2, 3]).to_smalltalk_string(), "a RingBuffer(2 3)");
+        assert_eq!(RingBuffer::new(2).to_smalltalk_string(), "a RingBuffer()");
+    }
+    
+    #[test]
+    fn test_ring_buffer_inspect() {
+        let buffer = filled(2, [7, 8, 9]);
+        assert_eq!(
+            buffer.inspect(),
+            vec![
+                ("1".to_string(), "8".to_string()),
+                ("2".to_string(), "9".to_string()),
+            ]
+        );
+    }
+}
\ No newline at end of file
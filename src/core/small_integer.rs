@@ -4,8 +4,22 @@
 //! In traditional Smalltalk, SmallIntegers are immediate values (not heap objects)
 //! for performance, but this implementation treats them as regular objects.
 
+use std::borrow::Cow;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::RangeInclusive;
+use std::sync::OnceLock;
+
+use super::exception::{ArithmeticError, DivisionError, ZeroDivide};
+use super::magnitude::Magnitude;
 use super::object::{ObjectId, SmalltalkObject};
 
+/// Range of values served from the shared SmallInteger cache
+/// 
+/// Values in this range are the ones most frequently produced by arithmetic
+/// and loop counters, so they are preallocated once with stable identities.
+pub const SMALL_INTEGER_CACHE_RANGE: RangeInclusive<i64> = -256..=1024;
+
 /// SmallInteger represents integer values in Smalltalk
 /// 
 /// SmallIntegers support basic arithmetic operations and comparisons.
@@ -38,6 +52,38 @@ impl SmallInteger {
         }
     }
     
+    /// Returns a shared SmallInteger for frequently used values
+    /// 
+    /// Values within `SMALL_INTEGER_CACHE_RANGE` are served from a cache
+    /// created on first use, so repeated calls return the identical object
+    /// and don't allocate new ObjectIds. Values outside the range are
+    /// allocated normally.
+    /// 
+    /// # Arguments
+    /// * `value` - The integer value to wrap
+    /// 
+    /// # Returns
+    /// A borrowed cached SmallInteger, or an owned new one outside the cache range
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::{SmallInteger, SmalltalkObject};
+    /// let a = SmallInteger::cached(5);
+    /// let b = SmallInteger::cached(5);
+    /// assert!(a.is_identical(&*b));
+    /// ```
+    pub fn cached(value: i64) -> Cow<'static, SmallInteger> {
+        static CACHE: OnceLock<Vec<SmallInteger>> = OnceLock::new();
+        
+        if !SMALL_INTEGER_CACHE_RANGE.contains(&value) {
+            return Cow::Owned(SmallInteger::new(value));
+        }
+        let cache = CACHE.get_or_init(|| {
+            SMALL_INTEGER_CACHE_RANGE.map(SmallInteger::new).collect()
+        });
+        Cow::Borrowed(&cache[(value - SMALL_INTEGER_CACHE_RANGE.start()) as usize])
+    }
+    
     /// Returns the integer value
     /// 
     /// # Returns
@@ -46,6 +92,35 @@ impl SmallInteger {
         self.value
     }
     
+    /// Returns the print string, shared for values in the cache range
+    /// 
+    /// Print strings for `SMALL_INTEGER_CACHE_RANGE` are formatted once into
+    /// a static table, so printing those values in hot logging paths neither
+    /// reformats nor allocates. Other values are formatted on each call.
+    /// 
+    /// # Returns
+    /// The decimal representation of this integer, borrowed from the table
+    /// when the value is in the cache range
+    /// 
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use smalltalkrs::core::SmallInteger;
+    /// assert!(matches!(SmallInteger::new(-42).cached_print_string(), Cow::Borrowed("-42")));
+    /// assert_eq!(SmallInteger::new(1_000_000).cached_print_string(), "1000000");
+    /// ```
+    pub fn cached_print_string(&self) -> Cow<'static, str> {
+        static PRINT_STRINGS: OnceLock<Vec<String>> = OnceLock::new();
+        
+        if !SMALL_INTEGER_CACHE_RANGE.contains(&self.value) {
+            return Cow::Owned(self.value.to_string());
+        }
+        let table = PRINT_STRINGS.get_or_init(|| {
+            SMALL_INTEGER_CACHE_RANGE.map(|value| value.to_string()).collect()
+        });
+        Cow::Borrowed(&table[(self.value - SMALL_INTEGER_CACHE_RANGE.start()) as usize])
+    }
+    
     /// Adds another SmallInteger to this one
     /// 
     /// Equivalent to Smalltalk's `+` message.
@@ -111,6 +186,437 @@ impl SmallInteger {
     pub fn less_than(&self, other: &SmallInteger) -> bool {
         self.value < other.value
     }
+    
+    /// Divides this integer by another, rounding toward negative infinity
+    /// 
+    /// Equivalent to Smalltalk's `//` message.
+    /// 
+    /// # Arguments
+    /// * `other` - The SmallInteger to divide by
+    /// 
+    /// # Returns
+    /// A new SmallInteger containing the floored quotient, or a
+    /// `DivisionError` wrapping `ZeroDivide` if `other` is zero or
+    /// `ArithmeticError` if the quotient doesn't fit (`i64::MIN // -1`)
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::SmallInteger;
+    /// let a = SmallInteger::new(-7);
+    /// let b = SmallInteger::new(2);
+    /// assert_eq!(a.divide(&b).unwrap().value(), -4);
+    /// assert!(a.divide(&SmallInteger::new(0)).is_err());
+    /// ```
+    pub fn divide(&self, other: &SmallInteger) -> Result<SmallInteger, DivisionError> {
+        if other.value == 0 {
+            return Err(ZeroDivide::new(self.value).into());
+        }
+        let quotient = self.value.checked_div(other.value).ok_or_else(|| {
+            ArithmeticError::new(&format!(
+                "Quotient of {} // {} doesn't fit in a SmallInteger",
+                self.value, other.value
+            ))
+        })?;
+        if self.value % other.value != 0 && (self.value < 0) != (other.value < 0) {
+            Ok(SmallInteger::new(quotient - 1))
+        } else {
+            Ok(SmallInteger::new(quotient))
+        }
+    }
+    
+    /// Returns the modulo of this integer by another
+    /// 
+    /// Equivalent to Smalltalk's `\\` message. The result has the sign of
+    /// the divisor, so that `(a // b) * b + (a \\ b) = a`.
+    /// 
+    /// # Arguments
+    /// * `other` - The SmallInteger to divide by
+    /// 
+    /// # Returns
+    /// A new SmallInteger containing the modulo, or a `ZeroDivide`
+    /// exception if `other` is zero
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::SmallInteger;
+    /// let a = SmallInteger::new(-7);
+    /// let b = SmallInteger::new(2);
+    /// assert_eq!(a.modulo(&b).unwrap().value(), 1);
+    /// ```
+    pub fn modulo(&self, other: &SmallInteger) -> Result<SmallInteger, ZeroDivide> {
+        if other.value == 0 {
+            return Err(ZeroDivide::new(self.value));
+        }
+        // wrapping_rem only differs from % for i64::MIN \\ -1, whose result is 0
+        let remainder = self.value.wrapping_rem(other.value);
+        if remainder != 0 && (remainder < 0) != (other.value < 0) {
+            Ok(SmallInteger::new(remainder + other.value))
+        } else {
+            Ok(SmallInteger::new(remainder))
+        }
+    }
+    
+    /// Returns the remainder of dividing this integer by another
+    /// 
+    /// Equivalent to Smalltalk's `rem:` message. The quotient is truncated
+    /// toward zero, so the result has the sign of the receiver.
+    /// 
+    /// # Arguments
+    /// * `other` - The SmallInteger to divide by
+    /// 
+    /// # Returns
+    /// A new SmallInteger containing the remainder, or a `ZeroDivide`
+    /// exception if `other` is zero
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::SmallInteger;
+    /// let a = SmallInteger::new(-7);
+    /// let b = SmallInteger::new(2);
+    /// assert_eq!(a.remainder(&b).unwrap().value(), -1);
+    /// ```
+    pub fn remainder(&self, other: &SmallInteger) -> Result<SmallInteger, ZeroDivide> {
+        if other.value == 0 {
+            return Err(ZeroDivide::new(self.value));
+        }
+        Ok(SmallInteger::new(self.value.wrapping_rem(other.value)))
+    }
+    
+    /// Truncates this integer toward zero to a multiple of `step`
+    /// 
+    /// Equivalent to Smalltalk's `truncateTo:` message.
+    /// 
+    /// # Arguments
+    /// * `step` - The SmallInteger whose multiple to truncate to
+    /// 
+    /// # Returns
+    /// A new SmallInteger containing the truncated value, or a `ZeroDivide`
+    /// exception if `step` is zero
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::SmallInteger;
+    /// let num = SmallInteger::new(47);
+    /// assert_eq!(num.truncate_to(&SmallInteger::new(10)).unwrap().value(), 40);
+    /// ```
+    pub fn truncate_to(&self, step: &SmallInteger) -> Result<SmallInteger, ZeroDivide> {
+        if step.value == 0 {
+            return Err(ZeroDivide::new(self.value));
+        }
+        // Subtracting the remainder moves toward zero, so this can't overflow
+        Ok(SmallInteger::new(self.value - self.value.wrapping_rem(step.value)))
+    }
+    
+    /// Rounds this integer to the nearest multiple of `step`
+    /// 
+    /// Equivalent to Smalltalk's `roundTo:` message. Halfway values are
+    /// rounded away from zero.
+    /// 
+    /// # Arguments
+    /// * `step` - The SmallInteger whose multiple to round to
+    /// 
+    /// # Returns
+    /// A new SmallInteger containing the rounded value, or a
+    /// `DivisionError` wrapping `ZeroDivide` if `step` is zero or
+    /// `ArithmeticError` if the rounded value doesn't fit
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::SmallInteger;
+    /// let num = SmallInteger::new(47);
+    /// assert_eq!(num.round_to(&SmallInteger::new(10)).unwrap().value(), 50);
+    /// ```
+    pub fn round_to(&self, step: &SmallInteger) -> Result<SmallInteger, DivisionError> {
+        if step.value == 0 {
+            return Err(ZeroDivide::new(self.value).into());
+        }
+        
+        // 128-bit intermediates so neither the quotient nor |step| overflows
+        let value = self.value as i128;
+        let step_value = step.value as i128;
+        let quotient = value / step_value;
+        let remainder = value % step_value;
+        let rounded = if 2 * remainder.abs() >= step_value.abs() {
+            if (value < 0) != (step_value < 0) {
+                quotient - 1
+            } else {
+                quotient + 1
+            }
+        } else {
+            quotient
+        };
+        
+        i64::try_from(rounded * step_value)
+            .map(SmallInteger::new)
+            .map_err(|_| {
+                ArithmeticError::new(&format!(
+                    "Result of {} roundTo: {} doesn't fit in a SmallInteger",
+                    self.value, step.value
+                ))
+                .into()
+            })
+    }
+    
+    /// Tests whether this integer is prime
+    /// 
+    /// Equivalent to Smalltalk's `isPrime`. Uses a Miller-Rabin test with a
+    /// fixed set of witnesses, which is exact for every 64-bit value.
+    /// Negative numbers, 0 and 1 are not prime.
+    /// 
+    /// # Returns
+    /// True if this integer is prime
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::SmallInteger;
+    /// assert!(SmallInteger::new(17).is_prime());
+    /// assert!(!SmallInteger::new(18).is_prime());
+    /// ```
+    pub fn is_prime(&self) -> bool {
+        is_prime_value(self.value)
+    }
+    
+    /// Returns the smallest prime greater than this integer
+    /// 
+    /// Equivalent to Pharo's `nextPrime`.
+    /// 
+    /// # Returns
+    /// A new SmallInteger containing the next prime, or an `ArithmeticError`
+    /// if that prime doesn't fit in a SmallInteger
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::SmallInteger;
+    /// assert_eq!(SmallInteger::new(13).next_prime().unwrap().value(), 17);
+    /// ```
+    pub fn next_prime(&self) -> Result<SmallInteger, ArithmeticError> {
+        let mut candidate = self.value.max(1);
+        loop {
+            candidate = candidate.checked_add(1).ok_or_else(|| {
+                ArithmeticError::new(&format!("No prime after {} fits in a SmallInteger", self.value))
+            })?;
+            if is_prime_value(candidate) {
+                return Ok(SmallInteger::new(candidate));
+            }
+        }
+    }
+    
+    /// Returns the floor of the square root of this integer
+    /// 
+    /// Equivalent to Smalltalk's `sqrtFloor`. Computed with integer
+    /// arithmetic only, so it is exact even for values too large to be
+    /// represented precisely as floats.
+    /// 
+    /// # Returns
+    /// A new SmallInteger containing the integer square root, or an
+    /// `ArithmeticError` if this integer is negative
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::SmallInteger;
+    /// assert_eq!(SmallInteger::new(17).integer_sqrt().unwrap().value(), 4);
+    /// assert!(SmallInteger::new(-4).integer_sqrt().is_err());
+    /// ```
+    pub fn integer_sqrt(&self) -> Result<SmallInteger, ArithmeticError> {
+        self.value
+            .checked_isqrt()
+            .map(SmallInteger::new)
+            .ok_or_else(|| {
+                ArithmeticError::new(&format!("Square root of negative number {}", self.value))
+            })
+    }
+    
+    /// Raises this integer to a power, modulo another integer
+    /// 
+    /// Equivalent to Smalltalk's `raisedTo:modulo:`. Uses square-and-multiply
+    /// with 128-bit intermediates, reducing at every step, so it never
+    /// overflows however large the exponent is. Like `\\`, the result has
+    /// the sign of the modulus.
+    /// 
+    /// # Arguments
+    /// * `exponent` - The non-negative power to raise to
+    /// * `modulus` - The SmallInteger to reduce by
+    /// 
+    /// # Returns
+    /// A new SmallInteger containing the modular power, or an
+    /// `ArithmeticError` if the exponent is negative or the modulus is zero
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::SmallInteger;
+    /// let base = SmallInteger::new(4);
+    /// let result = base.raised_to_modulo(&SmallInteger::new(13), &SmallInteger::new(497));
+    /// assert_eq!(result.unwrap().value(), 445);
+    /// ```
+    pub fn raised_to_modulo(
+        &self,
+        exponent: &SmallInteger,
+        modulus: &SmallInteger,
+    ) -> Result<SmallInteger, ArithmeticError> {
+        if exponent.value < 0 {
+            return Err(ArithmeticError::new(&format!(
+                "Negative exponent {} in modular power",
+                exponent.value
+            )));
+        }
+        if modulus.value == 0 {
+            return Err(ArithmeticError::new("Modular power with zero modulus"));
+        }
+        
+        let m = (modulus.value as i128).abs();
+        let mut result = 1 % m;
+        let mut base = (self.value as i128).rem_euclid(m);
+        let mut remaining = exponent.value;
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                result = result * base % m;
+            }
+            base = base * base % m;
+            remaining >>= 1;
+        }
+        
+        if result != 0 && modulus.value < 0 {
+            result -= m;
+        }
+        Ok(SmallInteger::new(result as i64))
+    }
+    
+    /// Prints this integer in a radix, padded on the left to a minimum width
+    /// 
+    /// Equivalent to Smalltalk's `printString:paddedWith:to:` style of
+    /// printing, as used for hex dumps. Digits above 9 are uppercase letters.
+    /// A negative sign comes before any padding and counts toward the width.
+    /// Values wider than `width` are printed in full, never truncated.
+    /// 
+    /// # Arguments
+    /// * `radix` - The base to print in, from 2 to 36
+    /// * `pad` - The character used to fill up to the width
+    /// * `width` - The minimum number of characters in the result
+    /// 
+    /// # Returns
+    /// The padded representation of this integer, or an `ArithmeticError`
+    /// if `radix` is outside 2..=36
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::SmallInteger;
+    /// let byte = SmallInteger::new(15);
+    /// assert_eq!(byte.print_string_radix_padded(16, '0', 2).unwrap(), "0F");
+    /// assert!(byte.print_string_radix_padded(37, '0', 2).is_err());
+    /// ```
+    pub fn print_string_radix_padded(
+        &self,
+        radix: u32,
+        pad: char,
+        width: usize,
+    ) -> Result<String, ArithmeticError> {
+        if !(2..=36).contains(&radix) {
+            return Err(ArithmeticError::new(&format!(
+                "Radix must be between 2 and 36, got {}",
+                radix
+            )));
+        }
+        
+        let mut digits = Vec::new();
+        let mut magnitude = self.value.unsigned_abs();
+        loop {
+            let digit = (magnitude % radix as u64) as u32;
+            digits.push(std::char::from_digit(digit, radix).unwrap().to_ascii_uppercase());
+            magnitude /= radix as u64;
+            if magnitude == 0 {
+                break;
+            }
+        }
+        
+        let sign = if self.value < 0 { "-" } else { "" };
+        let padding = width.saturating_sub(sign.len() + digits.len());
+        let mut result = String::from(sign);
+        result.extend(std::iter::repeat_n(pad, padding));
+        result.extend(digits.iter().rev());
+        Ok(result)
+    }
+}
+
+/// Tests a raw value for primality with Miller-Rabin
+/// 
+/// Shared by `is_prime` and `next_prime`, so candidates can be tested
+/// without allocating a SmallInteger for each.
+fn is_prime_value(value: i64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    
+    if value < 2 {
+        return false;
+    }
+    let n = value as u64;
+    for &p in &WITNESSES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+    
+    // Write n - 1 as d * 2^s with d odd
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    let mul_mod = |a: u64, b: u64| ((a as u128 * b as u128) % n as u128) as u64;
+    
+    WITNESSES.iter().all(|&witness| {
+        let mut x = 1;
+        let mut base = witness;
+        let mut exponent = d;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                x = mul_mod(x, base);
+            }
+            base = mul_mod(base, base);
+            exponent >>= 1;
+        }
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+impl From<i64> for SmallInteger {
+    fn from(value: i64) -> Self {
+        SmallInteger::new(value)
+    }
+}
+
+/// Rust `==` on SmallIntegers means Smalltalk's `=` (value equality)
+/// 
+/// It never compares object identity; use `is_identical` for Smalltalk's `==`.
+impl PartialEq for SmallInteger {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for SmallInteger {}
+
+/// Hashes the value, not the object id, so equal SmallIntegers hash alike
+impl Hash for SmallInteger {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl fmt::Display for SmallInteger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.cached_print_string())
+    }
+}
+
+impl Magnitude for SmallInteger {
+    fn less_than(&self, other: &SmallInteger) -> bool {
+        self.less_than(other)
+    }
 }
 
 impl SmalltalkObject for SmallInteger {
@@ -127,7 +633,11 @@ impl SmalltalkObject for SmallInteger {
     }
     
     fn to_smalltalk_string(&self) -> String {
-        self.value.to_string()
+        self.cached_print_string().into_owned()
+    }
+    
+    fn inspect(&self) -> Vec<(String, String)> {
+        vec![("value".to_string(), self.value.to_string())]
     }
 }
 
@@ -135,6 +645,7 @@ impl SmalltalkObject for SmallInteger {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use super::super::object::tests::WithId;
     
     #[test]
     fn test_small_integer_creation() {
@@ -142,6 +653,13 @@ mod tests {
         assert_eq!(num.value(), 42);
     }
     
+    #[test]
+    fn test_small_integer_from_i64() {
+        let num: SmallInteger = 42.into();
+        assert_eq!(num.value(), 42);
+        assert_eq!(SmallInteger::from(-3).value(), -3);
+    }
+    
     #[test]
     fn test_small_integer_unique_ids() {
         let num1 = SmallInteger::new(42);
@@ -151,6 +669,45 @@ mod tests {
         assert_ne!(num1.object_id(), num2.object_id());
     }
     
+    #[test]
+    fn test_cached_returns_identical_objects() {
+        let a = SmallInteger::cached(5);
+        let b = SmallInteger::cached(5);
+        
+        assert_eq!(a.value(), 5);
+        assert!(a.is_identical(&*b));
+        assert!(matches!(a, Cow::Borrowed(_)));
+    }
+    
+    #[test]
+    fn test_cached_range_bounds() {
+        let low = *SMALL_INTEGER_CACHE_RANGE.start();
+        let high = *SMALL_INTEGER_CACHE_RANGE.end();
+        
+        // Both ends of the range are cached with the right values
+        assert_eq!(SmallInteger::cached(low).value(), low);
+        assert_eq!(SmallInteger::cached(high).value(), high);
+        assert!(SmallInteger::cached(low).is_identical(&*SmallInteger::cached(low)));
+        assert!(SmallInteger::cached(high).is_identical(&*SmallInteger::cached(high)));
+    }
+    
+    #[test]
+    fn test_cached_outside_range_allocates() {
+        let beyond_high = *SMALL_INTEGER_CACHE_RANGE.end() + 1;
+        let beyond_low = *SMALL_INTEGER_CACHE_RANGE.start() - 1;
+        
+        for value in [beyond_high, beyond_low] {
+            let a = SmallInteger::cached(value);
+            let b = SmallInteger::cached(value);
+            
+            // Equal but distinct objects
+            assert_eq!(a.value(), value);
+            assert!(a.equals(&*b));
+            assert!(!a.is_identical(&*b));
+            assert!(matches!(a, Cow::Owned(_)));
+        }
+    }
+    
     #[test]
     fn test_small_integer_addition() {
         let a = SmallInteger::new(3);
@@ -189,6 +746,372 @@ mod tests {
         assert!(!a.less_than(&c)); // Equal values
     }
     
+    #[test]
+    fn test_small_integer_divide() {
+        let a = SmallInteger::new(17);
+        let b = SmallInteger::new(5);
+        
+        assert_eq!(a.divide(&b).unwrap().value(), 3);
+        
+        // Division rounds toward negative infinity
+        assert_eq!(SmallInteger::new(-17).divide(&b).unwrap().value(), -4);
+        assert_eq!(a.divide(&SmallInteger::new(-5)).unwrap().value(), -4);
+        assert_eq!(SmallInteger::new(-17).divide(&SmallInteger::new(-5)).unwrap().value(), 3);
+    }
+    
+    #[test]
+    fn test_small_integer_modulo() {
+        let b = SmallInteger::new(5);
+        
+        assert_eq!(SmallInteger::new(17).modulo(&b).unwrap().value(), 2);
+        
+        // Modulo takes the sign of the divisor
+        assert_eq!(SmallInteger::new(-17).modulo(&b).unwrap().value(), 3);
+        assert_eq!(SmallInteger::new(17).modulo(&SmallInteger::new(-5)).unwrap().value(), -3);
+        assert_eq!(SmallInteger::new(15).modulo(&SmallInteger::new(-5)).unwrap().value(), 0);
+    }
+    
+    #[test]
+    fn test_small_integer_remainder() {
+        let b = SmallInteger::new(5);
+        
+        assert_eq!(SmallInteger::new(17).remainder(&b).unwrap().value(), 2);
+        
+        // Remainder takes the sign of the receiver
+        assert_eq!(SmallInteger::new(-17).remainder(&b).unwrap().value(), -2);
+        assert_eq!(SmallInteger::new(17).remainder(&SmallInteger::new(-5)).unwrap().value(), 2);
+    }
+    
+    #[test]
+    fn test_division_identity() {
+        // (a // b) * b + (a \\ b) = a for all sign combinations
+        for (a, b) in [(17, 5), (-17, 5), (17, -5), (-17, -5), (0, 3)] {
+            let x = SmallInteger::new(a);
+            let y = SmallInteger::new(b);
+            let quotient = x.divide(&y).unwrap().value();
+            let modulo = x.modulo(&y).unwrap().value();
+            assert_eq!(quotient * b + modulo, a);
+        }
+    }
+    
+    #[test]
+    fn test_division_by_zero_signals_zero_divide() {
+        let a = SmallInteger::new(7);
+        let zero = SmallInteger::new(0);
+        
+        for result in [a.modulo(&zero), a.remainder(&zero)] {
+            match result {
+                Err(error) => {
+                    assert_eq!(error.dividend(), 7);
+                    assert_eq!(error.message_text(), "Attempt to divide 7 by zero");
+                }
+                Ok(_) => panic!("expected ZeroDivide"),
+            }
+        }
+        
+        match a.divide(&zero) {
+            Err(DivisionError::ZeroDivide(error)) => assert_eq!(error.dividend(), 7),
+            other => panic!("expected ZeroDivide, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_division_of_min_by_minus_one() {
+        let min = SmallInteger::new(i64::MIN);
+        let minus_one = SmallInteger::new(-1);
+        
+        // The quotient 2^63 doesn't fit, so // signals instead of panicking
+        match min.divide(&minus_one) {
+            Err(DivisionError::Overflow(error)) => assert_eq!(
+                error.message_text(),
+                "Quotient of -9223372036854775808 // -1 doesn't fit in a SmallInteger"
+            ),
+            other => panic!("expected overflow, got {:?}", other),
+        }
+        
+        // The remainder is exactly 0 either way
+        assert_eq!(min.modulo(&minus_one).unwrap().value(), 0);
+        assert_eq!(min.remainder(&minus_one).unwrap().value(), 0);
+    }
+    
+    #[test]
+    fn test_small_integer_truncate_to() {
+        let ten = SmallInteger::new(10);
+        
+        assert_eq!(SmallInteger::new(47).truncate_to(&ten).unwrap().value(), 40);
+        assert_eq!(SmallInteger::new(40).truncate_to(&ten).unwrap().value(), 40);
+        
+        // Truncation is toward zero
+        assert_eq!(SmallInteger::new(-47).truncate_to(&ten).unwrap().value(), -40);
+    }
+    
+    #[test]
+    fn test_small_integer_round_to() {
+        let ten = SmallInteger::new(10);
+        
+        assert_eq!(SmallInteger::new(47).round_to(&ten).unwrap().value(), 50);
+        assert_eq!(SmallInteger::new(43).round_to(&ten).unwrap().value(), 40);
+        assert_eq!(SmallInteger::new(-47).round_to(&ten).unwrap().value(), -50);
+        
+        // Halfway values round away from zero
+        assert_eq!(SmallInteger::new(45).round_to(&ten).unwrap().value(), 50);
+        assert_eq!(SmallInteger::new(-45).round_to(&ten).unwrap().value(), -50);
+    }
+    
+    #[test]
+    fn test_truncate_and_round_to_zero_step() {
+        let num = SmallInteger::new(47);
+        let zero = SmallInteger::new(0);
+        
+        assert_eq!(num.truncate_to(&zero).unwrap_err().dividend(), 47);
+        match num.round_to(&zero) {
+            Err(DivisionError::ZeroDivide(error)) => assert_eq!(error.dividend(), 47),
+            other => panic!("expected ZeroDivide, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_truncate_to_extremes() {
+        let min = SmallInteger::new(i64::MIN);
+        
+        // i64::MIN / -1 overflows, but the truncated multiple is i64::MIN itself
+        assert_eq!(min.truncate_to(&SmallInteger::new(-1)).unwrap().value(), i64::MIN);
+        assert_eq!(min.truncate_to(&min).unwrap().value(), i64::MIN);
+        let max = SmallInteger::new(i64::MAX);
+        assert_eq!(max.truncate_to(&SmallInteger::new(10)).unwrap().value(), 9_223_372_036_854_775_800);
+        assert_eq!(SmallInteger::new(5).truncate_to(&min).unwrap().value(), 0);
+    }
+    
+    #[test]
+    fn test_round_to_extremes() {
+        let min = SmallInteger::new(i64::MIN);
+        
+        // Rounding i64::MAX up to the next multiple of 10 doesn't fit
+        match SmallInteger::new(i64::MAX).round_to(&SmallInteger::new(10)) {
+            Err(DivisionError::Overflow(error)) => assert_eq!(
+                error.message_text(),
+                "Result of 9223372036854775807 roundTo: 10 doesn't fit in a SmallInteger"
+            ),
+            other => panic!("expected overflow, got {:?}", other),
+        }
+        
+        // A step of i64::MIN has no i64 absolute value but still rounds
+        assert_eq!(SmallInteger::new(5).round_to(&min).unwrap().value(), 0);
+        assert_eq!(min.round_to(&min).unwrap().value(), i64::MIN);
+        assert_eq!(min.round_to(&SmallInteger::new(-1)).unwrap().value(), i64::MIN);
+        assert!(matches!(
+            SmallInteger::new(i64::MAX).round_to(&min),
+            Err(DivisionError::Overflow(_))
+        ));
+    }
+    
+    #[test]
+    fn test_small_integer_clamp() {
+        let low = SmallInteger::new(0);
+        let high = SmallInteger::new(10);
+        
+        // Below, above, and within the range
+        assert_eq!(SmallInteger::new(-5).clamp(&low, &high).value(), 0);
+        assert_eq!(SmallInteger::new(15).clamp(&low, &high).value(), 10);
+        assert_eq!(SmallInteger::new(7).clamp(&low, &high).value(), 7);
+    }
+    
+    #[test]
+    fn test_small_integer_is_prime() {
+        assert!(SmallInteger::new(2).is_prime());
+        assert!(SmallInteger::new(17).is_prime());
+        assert!(!SmallInteger::new(18).is_prime());
+        
+        // Negative numbers, 0 and 1 are not prime
+        assert!(!SmallInteger::new(-7).is_prime());
+        assert!(!SmallInteger::new(0).is_prime());
+        assert!(!SmallInteger::new(1).is_prime());
+    }
+    
+    #[test]
+    fn test_is_prime_matches_trial_division() {
+        let trial_division = |n: i64| n >= 2 && (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0);
+        
+        for n in -10..2000 {
+            assert_eq!(SmallInteger::new(n).is_prime(), trial_division(n), "n = {}", n);
+        }
+    }
+    
+    #[test]
+    fn test_is_prime_large_values() {
+        // Largest prime below 2^63 and a Carmichael number
+        assert!(SmallInteger::new(9_223_372_036_854_775_783).is_prime());
+        assert!(!SmallInteger::new(i64::MAX).is_prime());
+        assert!(!SmallInteger::new(3_215_031_751).is_prime());
+        assert!(SmallInteger::new(1_000_000_007).is_prime());
+    }
+    
+    #[test]
+    fn test_small_integer_next_prime() {
+        assert_eq!(SmallInteger::new(13).next_prime().unwrap().value(), 17);
+        assert_eq!(SmallInteger::new(14).next_prime().unwrap().value(), 17);
+        assert_eq!(SmallInteger::new(0).next_prime().unwrap().value(), 2);
+        assert_eq!(SmallInteger::new(-20).next_prime().unwrap().value(), 2);
+        assert_eq!(SmallInteger::new(2).next_prime().unwrap().value(), 3);
+    }
+    
+    #[test]
+    fn test_next_prime_overflow() {
+        let near_max = SmallInteger::new(i64::MAX - 100);
+        assert_eq!(near_max.next_prime().unwrap().value(), 9_223_372_036_854_775_783);
+        
+        // No larger prime fits in an i64
+        let largest_prime = SmallInteger::new(9_223_372_036_854_775_783);
+        let error = largest_prime.next_prime().unwrap_err();
+        assert!(error.message_text().contains("9223372036854775783"));
+    }
+    
+    #[test]
+    fn test_integer_sqrt_perfect_squares() {
+        for root in [0, 1, 2, 12, 1000, 3_037_000_499] {
+            let square = SmallInteger::new(root * root);
+            assert_eq!(square.integer_sqrt().unwrap().value(), root);
+        }
+    }
+    
+    #[test]
+    fn test_integer_sqrt_around_squares() {
+        assert_eq!(SmallInteger::new(15).integer_sqrt().unwrap().value(), 3);
+        assert_eq!(SmallInteger::new(16).integer_sqrt().unwrap().value(), 4);
+        assert_eq!(SmallInteger::new(17).integer_sqrt().unwrap().value(), 4);
+        
+        // Exact where a float square root would round up
+        let just_below = SmallInteger::new(3_037_000_499 * 3_037_000_499 - 1);
+        assert_eq!(just_below.integer_sqrt().unwrap().value(), 3_037_000_498);
+        assert_eq!(SmallInteger::new(i64::MAX).integer_sqrt().unwrap().value(), 3_037_000_499);
+    }
+    
+    #[test]
+    fn test_integer_sqrt_negative_signals_error() {
+        let error = SmallInteger::new(-4).integer_sqrt().unwrap_err();
+        assert_eq!(error.message_text(), "Square root of negative number -4");
+    }
+    
+    #[test]
+    fn test_cached_print_string() {
+        // Values in the cache range share one static string
+        let a = SmallInteger::new(-256);
+        let b = SmallInteger::new(-256);
+        match (a.cached_print_string(), b.cached_print_string()) {
+            (Cow::Borrowed(first), Cow::Borrowed(second)) => {
+                assert_eq!(first, "-256");
+                assert!(std::ptr::eq(first, second));
+            }
+            other => panic!("expected borrowed strings, got {:?}", other),
+        }
+        
+        // Values outside the range are formatted on demand
+        let large = SmallInteger::new(1025);
+        assert!(matches!(large.cached_print_string(), Cow::Owned(ref s) if s == "1025"));
+    }
+    
+    #[test]
+    fn test_small_integer_stays_two_words() {
+        // Print-string caching must not add per-object storage
+        assert_eq!(std::mem::size_of::<SmallInteger>(), 16);
+    }
+    
+    #[test]
+    fn test_cached_print_string_matches_fresh_formatting() {
+        for value in [-257, -256, -1, 0, 7, 1024, 1025, i64::MIN, i64::MAX] {
+            let num = SmallInteger::new(value);
+            assert_eq!(num.cached_print_string(), value.to_string());
+            assert_eq!(num.to_smalltalk_string(), value.to_string());
+            assert_eq!(format!("{}", num), value.to_string());
+        }
+    }
+    
+    #[test]
+    fn test_raised_to_modulo() {
+        let base = SmallInteger::new(4);
+        let result = base.raised_to_modulo(&SmallInteger::new(13), &SmallInteger::new(497));
+        assert_eq!(result.unwrap().value(), 445);
+        
+        // Anything to the 0th power is 1, reduced by the modulus
+        let zero = SmallInteger::new(0);
+        assert_eq!(base.raised_to_modulo(&zero, &SmallInteger::new(7)).unwrap().value(), 1);
+        assert_eq!(base.raised_to_modulo(&zero, &SmallInteger::new(1)).unwrap().value(), 0);
+    }
+    
+    #[test]
+    fn test_raised_to_modulo_large_values() {
+        // 2^(p-1) mod p = 1 for prime p (Fermat); naive 2^(p-1) overflows
+        let p = SmallInteger::new(1_000_000_007);
+        let exponent = p.subtract(&SmallInteger::new(1));
+        let result = SmallInteger::new(2).raised_to_modulo(&exponent, &p);
+        assert_eq!(result.unwrap().value(), 1);
+        
+        // A modulus near i64::MAX would overflow 64-bit multiplication
+        let m = SmallInteger::new(i64::MAX);
+        let base = SmallInteger::new(i64::MAX - 1);
+        let result = base.raised_to_modulo(&SmallInteger::new(2), &m);
+        assert_eq!(result.unwrap().value(), 1); // (-1)^2 mod m
+    }
+    
+    #[test]
+    fn test_raised_to_modulo_signs() {
+        // Negative base is reduced first: (-2)^3 = -8, -8 \\ 5 = 2
+        let result = SmallInteger::new(-2).raised_to_modulo(&SmallInteger::new(3), &SmallInteger::new(5));
+        assert_eq!(result.unwrap().value(), 2);
+        
+        // Result takes the sign of the modulus, like modulo: 8 \\ -5 = -2
+        let result = SmallInteger::new(2).raised_to_modulo(&SmallInteger::new(3), &SmallInteger::new(-5));
+        assert_eq!(result.unwrap().value(), -2);
+    }
+    
+    #[test]
+    fn test_raised_to_modulo_invalid_arguments() {
+        let base = SmallInteger::new(3);
+        
+        let error = base.raised_to_modulo(&SmallInteger::new(-1), &SmallInteger::new(7)).unwrap_err();
+        assert_eq!(error.message_text(), "Negative exponent -1 in modular power");
+        
+        let error = base.raised_to_modulo(&SmallInteger::new(2), &SmallInteger::new(0)).unwrap_err();
+        assert_eq!(error.message_text(), "Modular power with zero modulus");
+    }
+    
+    #[test]
+    fn test_print_string_radix_padded() {
+        // 15 printString: 16 paddedWith: $0 to: 2
+        assert_eq!(SmallInteger::new(15).print_string_radix_padded(16, '0', 2).unwrap(), "0F");
+        assert_eq!(SmallInteger::new(255).print_string_radix_padded(16, '0', 2).unwrap(), "FF");
+        assert_eq!(SmallInteger::new(5).print_string_radix_padded(2, '0', 8).unwrap(), "00000101");
+        assert_eq!(SmallInteger::new(42).print_string_radix_padded(10, ' ', 5).unwrap(), "   42");
+        assert_eq!(SmallInteger::new(0).print_string_radix_padded(16, '0', 4).unwrap(), "0000");
+        assert_eq!(SmallInteger::new(35).print_string_radix_padded(36, '0', 1).unwrap(), "Z");
+    }
+    
+    #[test]
+    fn test_print_string_radix_padded_never_truncates() {
+        assert_eq!(SmallInteger::new(4096).print_string_radix_padded(16, '0', 2).unwrap(), "1000");
+        assert_eq!(SmallInteger::new(42).print_string_radix_padded(10, '0', 0).unwrap(), "42");
+    }
+    
+    #[test]
+    fn test_print_string_radix_padded_negative() {
+        // Sign comes first and counts toward the width
+        assert_eq!(SmallInteger::new(-15).print_string_radix_padded(16, '0', 4).unwrap(), "-00F");
+        assert_eq!(
+            SmallInteger::new(i64::MIN).print_string_radix_padded(16, '0', 1).unwrap(),
+            "-8000000000000000"
+        );
+    }
+    
+    #[test]
+    fn test_print_string_radix_padded_invalid_radix() {
+        let num = SmallInteger::new(15);
+        
+        let error = num.print_string_radix_padded(37, '0', 2).unwrap_err();
+        assert_eq!(error.message_text(), "Radix must be between 2 and 36, got 37");
+        assert!(num.print_string_radix_padded(1, '0', 2).is_err());
+        assert!(num.print_string_radix_padded(0, '0', 2).is_err());
+    }
+    
     #[test]
     fn test_small_integer_equality() {
         let a = SmallInteger::new(42);
@@ -206,6 +1129,16 @@ mod tests {
         assert!(a.equals(&a));
     }
     
+    #[test]
+    fn test_equals_ignores_foreign_object_with_same_id() {
+        let num = SmallInteger::new(7);
+        // Ids from another ObjectSpace can collide with global ones
+        let foreign = WithId::new(num.object_id());
+        
+        assert!(num.is_identical(&foreign));
+        assert!(!num.equals(&foreign));
+    }
+    
     #[test]
     fn test_small_integer_identity_vs_equality() {
         let a = SmallInteger::new(42);
@@ -219,6 +1152,38 @@ mod tests {
         assert!(a.is_identical(&a));
     }
     
+    #[test]
+    fn test_partial_eq_matches_smalltalk_equals() {
+        let a = SmallInteger::new(1);
+        let b = SmallInteger::new(1);
+        let c = SmallInteger::new(2);
+        
+        assert!(a == b);
+        assert!(a != c);
+        assert_eq!(a == b, a.equals(&b));
+        assert_eq!(a == c, a.equals(&c));
+        
+        // Rust == is Smalltalk =, not identity
+        assert!(!a.is_identical(&b));
+    }
+    
+    #[test]
+    fn test_equal_small_integers_share_hash_map_key() {
+        use std::collections::HashMap;
+        
+        let mut map = HashMap::new();
+        map.insert(SmallInteger::new(5), "five");
+        
+        // A fresh object with the same value finds the entry
+        assert_eq!(map.get(&SmallInteger::new(5)), Some(&"five"));
+        assert_eq!(map.get(&SmallInteger::new(6)), None);
+        
+        // Inserting an equal key replaces rather than duplicates
+        map.insert(SmallInteger::new(5), "cinco");
+        assert_eq!(map.len(), 1);
+        assert_eq!(map[&SmallInteger::new(5)], "cinco");
+    }
+    
     #[test]
     fn test_small_integer_to_string() {
         let num = SmallInteger::new(42);
@@ -231,6 +1196,24 @@ mod tests {
         assert_eq!(zero.to_smalltalk_string(), "0");
     }
     
+    #[test]
+    fn test_small_integer_display_string() {
+        let num = SmallInteger::new(-5);
+        assert_eq!(num.display_string(), "-5");
+    }
+    
+    #[test]
+    fn test_small_integer_display() {
+        assert_eq!(format!("{}", SmallInteger::new(42)), "42");
+        assert_eq!(SmallInteger::new(-17).to_string(), "-17");
+    }
+    
+    #[test]
+    fn test_small_integer_inspect() {
+        let num = SmallInteger::new(42);
+        assert_eq!(num.inspect(), vec![("value".to_string(), "42".to_string())]);
+    }
+    
     #[test]
     fn test_operations_dont_mutate_original() {
         let original = SmallInteger::new(5);
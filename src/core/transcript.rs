@@ -0,0 +1,289 @@
+//! Transcript implementation for Smalltalk
+//! 
+//! The Transcript is Smalltalk's global text output stream, used for logging
+//! and quick printing (e.g. `Transcript show: 'hello'; cr`). Here it writes
+//! to standard output by default, but the sink can be any `Write`
+//! implementation so output can be captured.
+
+use std::fmt;
+use std::io::{self, Write};
+use std::sync::{Mutex, OnceLock};
+
+use super::object::{ObjectId, SmalltalkObject};
+
+/// Text output stream equivalent to Smalltalk's `Transcript`
+/// 
+/// All methods take `&self` and return the transcript, so calls can be
+/// chained like a Smalltalk cascade. Write errors are ignored, as a
+/// Transcript is a best-effort logging channel.
+pub struct Transcript {
+    id: ObjectId,
+    sink: Mutex<Box<dyn Write + Send>>,
+}
+
+impl Transcript {
+    /// Creates a new Transcript writing to standard output
+    /// 
+    /// # Returns
+    /// A new Transcript backed by stdout
+    pub fn new() -> Self {
+        Self::with_sink(Box::new(io::stdout()))
+    }
+    
+    /// Returns the global Transcript
+    /// 
+    /// This is the object Smalltalk code refers to as `Transcript`, and the
+    /// destination of `print_nl` and `display_nl`. It writes to stdout until
+    /// redirected with `set_sink`.
+    /// 
+    /// # Returns
+    /// A reference to the shared global Transcript
+    pub fn current() -> &'static Transcript {
+        static CURRENT: OnceLock<Transcript> = OnceLock::new();
+        CURRENT.get_or_init(Transcript::new)
+    }
+    
+    /// Creates a new Transcript writing to the given sink
+    /// 
+    /// # Arguments
+    /// * `sink` - Destination for all text written to the transcript
+    /// 
+    /// # Returns
+    /// A new Transcript backed by the sink
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::Transcript;
+    /// let transcript = Transcript::with_sink(Box::new(std::io::sink()));
+    /// transcript.show("hi").cr();
+    /// ```
+    pub fn with_sink(sink: Box<dyn Write + Send>) -> Self {
+        Self {
+            id: ObjectId::new(),
+            sink: Mutex::new(sink),
+        }
+    }
+    
+    /// Redirects all further output to a new sink
+    /// 
+    /// Output already written stays in the previous sink, which is flushed
+    /// and handed back so callers can restore or inspect it.
+    /// 
+    /// # Arguments
+    /// * `sink` - Destination for text written from now on
+    /// 
+    /// # Returns
+    /// The sink that was in use before the switch
+    /// 
+    /// # Examples
+    /// ```
+    /// use smalltalkrs::core::Transcript;
+    /// let transcript = Transcript::new();
+    /// let stdout = transcript.set_sink(Box::new(std::io::sink()));
+    /// transcript.show("discarded");
+    /// transcript.set_sink(stdout);
+    /// ```
+    pub fn set_sink(&self, sink: Box<dyn Write + Send>) -> Box<dyn Write + Send> {
+        let mut current = self.sink.lock().unwrap();
+        let _ = current.flush();
+        std::mem::replace(&mut *current, sink)
+    }
+    
+    /// Writes a string (equivalent to Smalltalk's `show:`)
+    /// 
+    /// # Arguments
+    /// * `text` - The text to write
+    /// 
+    /// # Returns
+    /// This transcript, for chaining
+    pub fn show(&self, text: &str) -> &Self {
+        let _ = self.sink.lock().unwrap().write_all(text.as_bytes());
+        self
+    }
+    
+    /// Writes an object's display string (counterpart of Smalltalk's `print:`)
+    /// 
+    /// Writes the user-facing `display_string`; pass `to_smalltalk_string`
+    /// to `show` to write the print string instead.
+    /// 
+    /// # Arguments
+    /// * `object` - The object to print
+    /// 
+    /// # Returns
+    /// This transcript, for chaining
+    pub fn print(&self, object: &dyn SmalltalkObject) -> &Self {
+        self.show(&object.display_string())
+    }
+    
+    /// Writes a newline (equivalent to Smalltalk's `cr`)
+    /// 
+    /// # Returns
+    /// This transcript, for chaining
+    pub fn cr(&self) -> &Self {
+        self.show("\n")
+    }
+    
+    /// Writes a tab character (equivalent to Smalltalk's `tab`)
+    /// 
+    /// # Returns
+    /// This transcript, for chaining
+    pub fn tab(&self) -> &Self {
+        self.show("\t")
+    }
+    
+    /// Writes a space character (equivalent to Smalltalk's `space`)
+    /// 
+    /// # Returns
+    /// This transcript, for chaining
+    pub fn space(&self) -> &Self {
+        self.show(" ")
+    }
+    
+    /// Flushes buffered output to the sink (equivalent to Smalltalk's `flush`)
+    /// 
+    /// # Returns
+    /// This transcript, for chaining
+    pub fn flush(&self) -> &Self {
+        let _ = self.sink.lock().unwrap().flush();
+        self
+    }
+}
+
+impl fmt::Debug for Transcript {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Transcript").field("id", &self.id).finish()
+    }
+}
+
+impl SmalltalkObject for Transcript {
+    fn object_id(&self) -> ObjectId {
+        self.id
+    }
+    
+    fn to_smalltalk_string(&self) -> String {
+        "a Transcript".to_string()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use super::super::boolean::False;
+    use super::super::small_integer::SmallInteger;
+    use std::sync::Arc;
+    
+    /// In-memory sink whose contents remain readable after being handed to a Transcript
+    #[derive(Debug, Clone, Default)]
+    pub(crate) struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+    
+    impl SharedBuffer {
+        pub(crate) fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+    
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+        
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+    
+    #[test]
+    fn test_show_then_cr() {
+        let buffer = SharedBuffer::default();
+        let transcript = Transcript::with_sink(Box::new(buffer.clone()));
+        
+        // Transcript show: 'hi'; cr
+        transcript.show("hi").cr();
+        
+        assert_eq!(buffer.contents(), "hi\n");
+    }
+    
+    #[test]
+    fn test_tab_and_space() {
+        let buffer = SharedBuffer::default();
+        let transcript = Transcript::with_sink(Box::new(buffer.clone()));
+        
+        transcript.show("a").tab().show("b").space().show("c");
+        
+        assert_eq!(buffer.contents(), "a\tb c");
+    }
+    
+    /// Object whose display string differs from its print string
+    #[derive(Debug)]
+    struct Greeting {
+        id: ObjectId,
+    }
+    
+    impl SmalltalkObject for Greeting {
+        fn object_id(&self) -> ObjectId {
+            self.id
+        }
+        
+        fn display_string(&self) -> String {
+            "hello".to_string()
+        }
+    }
+    
+    #[test]
+    fn test_print_uses_display_string() {
+        let buffer = SharedBuffer::default();
+        let transcript = Transcript::with_sink(Box::new(buffer.clone()));
+        let greeting = Greeting { id: ObjectId::new() };
+        
+        transcript
+            .print(&SmallInteger::new(42))
+            .space()
+            .print(&False::new())
+            .space()
+            .print(&greeting)
+            .flush();
+        
+        assert_eq!(buffer.contents(), "42 false hello");
+        assert_ne!(greeting.to_smalltalk_string(), "hello");
+    }
+    
+    #[test]
+    fn test_set_sink_captures_everything_printed() {
+        let buffer = SharedBuffer::default();
+        let transcript = Transcript::with_sink(Box::new(io::sink()));
+        
+        transcript.set_sink(Box::new(buffer.clone()));
+        for i in 1..=3 {
+            transcript.print(&SmallInteger::new(i)).cr();
+        }
+        
+        assert_eq!(buffer.contents(), "1\n2\n3\n");
+    }
+    
+    #[test]
+    fn test_switching_sinks_mid_run() {
+        let first = SharedBuffer::default();
+        let second = SharedBuffer::default();
+        let transcript = Transcript::with_sink(Box::new(first.clone()));
+        
+        transcript.show("before").cr();
+        let previous = transcript.set_sink(Box::new(second.clone()));
+        transcript.show("after").cr();
+        
+        // Each sink only receives the output written while it was active
+        assert_eq!(first.contents(), "before\n");
+        assert_eq!(second.contents(), "after\n");
+        
+        // Restoring the previous sink resumes writing to it
+        transcript.set_sink(previous);
+        transcript.show("again");
+        assert_eq!(first.contents(), "before\nagain");
+        assert_eq!(second.contents(), "after\n");
+    }
+    
+    #[test]
+    fn test_transcript_to_string() {
+        let transcript = Transcript::with_sink(Box::new(io::sink()));
+        assert_eq!(transcript.to_smalltalk_string(), "a Transcript");
+    }
+}
\ No newline at end of file
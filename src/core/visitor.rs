@@ -0,0 +1,225 @@
+//! Visitor pattern over Smalltalk object graphs
+//! 
+//! A Visitor receives a typed callback for every object reachable from the
+//! object it is passed to, so graph analyses such as size counting or class
+//! histograms don't need to downcast objects themselves. Traversal is done
+//! by `SmalltalkObject::accept` (or `accept_dyn` on trait objects), which
+//! walks `child_objects`.
+
+use std::collections::HashSet;
+
+use super::association::Association;
+use super::boolean::{False, True};
+use super::object::{ObjectId, SmalltalkObject};
+use super::ring_buffer::RingBuffer;
+use super::small_integer::SmallInteger;
+
+/// Callbacks for each kind of object in an object graph
+/// 
+/// Every method has a default, so visitors only override the kinds they
+/// care about. Specific methods fall back to more general ones:
+/// `visit_ring_buffer` to `visit_collection`, and everything eventually to
+/// `visit_object`, which ignores the object by default.
+pub trait Visitor {
+    /// Visits an object with no more specific callback
+    /// 
+    /// # Arguments
+    /// * `object` - The object being visited
+    fn visit_object(&mut self, object: &dyn SmalltalkObject) {
+        let _ = object;
+    }
+    
+    /// Visits a SmallInteger
+    /// 
+    /// # Arguments
+    /// * `integer` - The SmallInteger being visited
+    fn visit_small_integer(&mut self, integer: &SmallInteger) {
+        self.visit_object(integer);
+    }
+    
+    /// Visits the True object
+    /// 
+    /// # Arguments
+    /// * `value` - The True object being visited
+    fn visit_true(&mut self, value: &True) {
+        self.visit_object(value);
+    }
+    
+    /// Visits the False object
+    /// 
+    /// # Arguments
+    /// * `value` - The False object being visited
+    fn visit_false(&mut self, value: &False) {
+        self.visit_object(value);
+    }
+    
+    /// Visits an Association, before its key and value
+    /// 
+    /// # Arguments
+    /// * `association` - The Association being visited
+    fn visit_association(&mut self, association: &Association) {
+        self.visit_object(association);
+    }
+    
+    /// Visits any collection, before its elements
+    /// 
+    /// # Arguments
+    /// * `collection` - The collection being visited
+    fn visit_collection(&mut self, collection: &dyn SmalltalkObject) {
+        self.visit_object(collection);
+    }
+    
+    /// Visits a RingBuffer, before its elements
+    /// 
+    /// # Arguments
+    /// * `buffer` - The RingBuffer being visited
+    fn visit_ring_buffer(&mut self, buffer: &RingBuffer) {
+        self.visit_collection(buffer);
+    }
+}
+
+/// Visits `object` and everything reachable from it not yet in `visited`
+/// 
+/// Used by `accept` and `accept_dyn`. Objects are visited before their
+/// children, and each object id only once, so shared objects are visited
+/// once and cycles terminate.
+pub(crate) fn walk<V: Visitor + ?Sized>(
+    object: &dyn SmalltalkObject,
+    visitor: &mut V,
+    visited: &mut HashSet<ObjectId>,
+) {
+    if !visited.insert(object.object_id()) {
+        return;
+    }
+    
+    let any = object.as_any();
+    if let Some(integer) = any.downcast_ref::<SmallInteger>() {
+        visitor.visit_small_integer(integer);
+    } else if let Some(value) = any.downcast_ref::<True>() {
+        visitor.visit_true(value);
+    } else if let Some(value) = any.downcast_ref::<False>() {
+        visitor.visit_false(value);
+    } else if let Some(association) = any.downcast_ref::<Association>() {
+        visitor.visit_association(association);
+    } else if let Some(buffer) = any.downcast_ref::<RingBuffer>() {
+        visitor.visit_ring_buffer(buffer);
+    } else {
+        visitor.visit_object(object);
+    }
+    
+    for (_, child) in object.child_objects() {
+        walk(child, visitor, visited);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::object::tests::SelfReferencing;
+    use std::collections::HashMap;
+    
+    /// Counts visited objects by class
+    #[derive(Default)]
+    struct ClassHistogram {
+        counts: HashMap<&'static str, usize>,
+    }
+    
+    impl ClassHistogram {
+        fn count(&mut self, class_name: &'static str) {
+            *self.counts.entry(class_name).or_insert(0) += 1;
+        }
+    }
+    
+    impl Visitor for ClassHistogram {
+        fn visit_object(&mut self, _object: &dyn SmalltalkObject) {
+            self.count("Object");
+        }
+        
+        fn visit_small_integer(&mut self, _integer: &SmallInteger) {
+            self.count("SmallInteger");
+        }
+        
+        fn visit_true(&mut self, _value: &True) {
+            self.count("True");
+        }
+        
+        fn visit_false(&mut self, _value: &False) {
+            self.count("False");
+        }
+        
+        fn visit_association(&mut self, _association: &Association) {
+            self.count("Association");
+        }
+        
+        fn visit_ring_buffer(&mut self, _buffer: &RingBuffer) {
+            self.count("RingBuffer");
+        }
+    }
+    
+    #[test]
+    fn test_class_histogram_over_nested_structure() {
+        // a RingBuffer(1 true (2->false) a RingBuffer(3 4))
+        let mut inner = RingBuffer::new(2);
+        inner.add(Box::new(SmallInteger::new(3)));
+        inner.add(Box::new(SmallInteger::new(4)));
+        let mut outer = RingBuffer::new(4);
+        outer.add(Box::new(SmallInteger::new(1)));
+        outer.add(Box::new(True::new()));
+        outer.add(Box::new(SmallInteger::new(2).associate(Box::new(False::new()))));
+        outer.add(Box::new(inner));
+        
+        let mut histogram = ClassHistogram::default();
+        outer.accept(&mut histogram);
+        
+        let expected = HashMap::from([
+            ("RingBuffer", 2),
+            ("SmallInteger", 4),
+            ("True", 1),
+            ("False", 1),
+            ("Association", 1),
+        ]);
+        assert_eq!(histogram.counts, expected);
+    }
+    
+    #[test]
+    fn test_default_callbacks_fall_back_to_visit_object() {
+        /// Only overrides the most general callbacks
+        #[derive(Default)]
+        struct Counter {
+            objects: usize,
+            collections: usize,
+        }
+        
+        impl Visitor for Counter {
+            fn visit_object(&mut self, _object: &dyn SmalltalkObject) {
+                self.objects += 1;
+            }
+            
+            fn visit_collection(&mut self, collection: &dyn SmalltalkObject) {
+                self.collections += 1;
+                self.visit_object(collection);
+            }
+        }
+        
+        let mut buffer = RingBuffer::new(3);
+        buffer.add(Box::new(SmallInteger::new(1)));
+        buffer.add(Box::new(True::new().associate(Box::new(SmallInteger::new(2)))));
+        
+        let mut counter = Counter::default();
+        let object: Box<dyn SmalltalkObject> = Box::new(buffer);
+        object.accept_dyn(&mut counter);
+        
+        // buffer, 1, association, true, 2
+        assert_eq!(counter.objects, 5);
+        assert_eq!(counter.collections, 1);
+    }
+    
+    #[test]
+    fn test_each_object_visited_once() {
+        let obj = SelfReferencing::new();
+        let mut histogram = ClassHistogram::default();
+        obj.accept(&mut histogram);
+        
+        assert_eq!(histogram.counts, HashMap::from([("Object", 1)]));
+    }
+}
\ No newline at end of file